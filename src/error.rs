@@ -0,0 +1,368 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use messages;
+use options::Options;
+use params::Value;
+
+/// The rule that a `ValidationError` was produced by.
+///
+/// Each variant corresponds to the `Rule` of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ErrorKind {
+    Accepted,
+    ActiveUrl,
+    After,
+    AfterOrEqual,
+    Alpha,
+    AlphaDash,
+    AlphaNumeric,
+    Array,
+    Ascii,
+    Before,
+    BeforeOrEqual,
+    Between,
+    Boolean,
+    Confirmed,
+    /// A user-defined rule, identified by the given code.
+    Custom(&'static str),
+    Date,
+    DateEquals,
+    DateFormat,
+    Decimal,
+    Different,
+    Digits,
+    DigitsBetween,
+    Dimensions,
+    Distinct,
+    DoesntEndWith,
+    DoesntStartWith,
+    Email,
+    EndsWith,
+    Exists,
+    File,
+    Filled,
+    Gt,
+    Gte,
+    Image,
+    In,
+    InArray,
+    Integer,
+    IpAddress,
+    Json,
+    Lowercase,
+    Lt,
+    Lte,
+    MacAddress,
+    Max,
+    MaxFileSize,
+    /// The contents of an uploaded file do not agree with its declared type or extension.
+    MimeMismatch,
+    MimeTypes,
+    Mimes,
+    Min,
+    MinFileSize,
+    MultipleOf,
+    NotIn,
+    NotInArray,
+    Numeric,
+    Present,
+    Regex,
+    Required,
+    RequiredIf,
+    RequiredUnless,
+    RequiredWith,
+    RequiredWithAll,
+    RequiredWithout,
+    RequiredWithoutAll,
+    Same,
+    Size,
+    StartsWith,
+    String,
+    Timezone,
+    Ulid,
+    Unique,
+    Uppercase,
+    Url,
+    Uuid,
+}
+
+impl ErrorKind {
+    /// A stable, machine-readable code for this kind of error.
+    ///
+    /// Codes match the rule names accepted by `Rule::parse`, such as `required` or `alpha_num`.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            ErrorKind::Accepted => "accepted",
+            ErrorKind::ActiveUrl => "active_url",
+            ErrorKind::After => "after",
+            ErrorKind::AfterOrEqual => "after_or_equal",
+            ErrorKind::Alpha => "alpha",
+            ErrorKind::AlphaDash => "alpha_dash",
+            ErrorKind::AlphaNumeric => "alpha_num",
+            ErrorKind::Array => "array",
+            ErrorKind::Ascii => "ascii",
+            ErrorKind::Before => "before",
+            ErrorKind::BeforeOrEqual => "before_or_equal",
+            ErrorKind::Between => "between",
+            ErrorKind::Boolean => "boolean",
+            ErrorKind::Confirmed => "confirmed",
+            ErrorKind::Custom(code) => code,
+            ErrorKind::Date => "date",
+            ErrorKind::DateEquals => "date_equals",
+            ErrorKind::DateFormat => "date_format",
+            ErrorKind::Decimal => "decimal",
+            ErrorKind::Different => "different",
+            ErrorKind::Digits => "digits",
+            ErrorKind::DigitsBetween => "digits_between",
+            ErrorKind::Dimensions => "dimensions",
+            ErrorKind::Distinct => "distinct",
+            ErrorKind::DoesntEndWith => "doesnt_end_with",
+            ErrorKind::DoesntStartWith => "doesnt_start_with",
+            ErrorKind::Email => "email",
+            ErrorKind::EndsWith => "ends_with",
+            ErrorKind::Exists => "exists",
+            ErrorKind::File => "file",
+            ErrorKind::Filled => "filled",
+            ErrorKind::Gt => "gt",
+            ErrorKind::Gte => "gte",
+            ErrorKind::Image => "image",
+            ErrorKind::In => "in",
+            ErrorKind::InArray => "in_array",
+            ErrorKind::Integer => "integer",
+            ErrorKind::IpAddress => "ip",
+            ErrorKind::Json => "json",
+            ErrorKind::Lowercase => "lowercase",
+            ErrorKind::Lt => "lt",
+            ErrorKind::Lte => "lte",
+            ErrorKind::MacAddress => "mac_address",
+            ErrorKind::Max => "max",
+            ErrorKind::MaxFileSize => "max_file_size",
+            ErrorKind::MimeMismatch => "mime_mismatch",
+            ErrorKind::MimeTypes => "mimetypes",
+            ErrorKind::Mimes => "mimes",
+            ErrorKind::Min => "min",
+            ErrorKind::MinFileSize => "min_file_size",
+            ErrorKind::MultipleOf => "multiple_of",
+            ErrorKind::NotIn => "not_in",
+            ErrorKind::NotInArray => "not_in_array",
+            ErrorKind::Numeric => "numeric",
+            ErrorKind::Present => "present",
+            ErrorKind::Regex => "regex",
+            ErrorKind::Required => "required",
+            ErrorKind::RequiredIf => "required_if",
+            ErrorKind::RequiredUnless => "required_unless",
+            ErrorKind::RequiredWith => "required_with",
+            ErrorKind::RequiredWithAll => "required_with_all",
+            ErrorKind::RequiredWithout => "required_without",
+            ErrorKind::RequiredWithoutAll => "required_without_all",
+            ErrorKind::Same => "same",
+            ErrorKind::Size => "size",
+            ErrorKind::StartsWith => "starts_with",
+            ErrorKind::String => "string",
+            ErrorKind::Timezone => "timezone",
+            ErrorKind::Ulid => "ulid",
+            ErrorKind::Unique => "unique",
+            ErrorKind::Uppercase => "uppercase",
+            ErrorKind::Url => "url",
+            ErrorKind::Uuid => "uuid",
+        }
+    }
+
+    /// Whether the message for this kind of error depends on the type of the value,
+    /// as with `Size`, where strings are measured in characters and files in kilobytes.
+    fn is_sized(&self) -> bool {
+        matches!(*self,
+                 ErrorKind::Between | ErrorKind::Gt | ErrorKind::Gte | ErrorKind::Lt |
+                 ErrorKind::Lte | ErrorKind::Max | ErrorKind::Min | ErrorKind::Size)
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// The type of the value that failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    Null,
+    Boolean,
+    I64,
+    U64,
+    F64,
+    String,
+    File,
+    Array,
+    Map,
+}
+
+impl ValueType {
+    /// Returns the type of `value`.
+    pub fn of(value: &Value) -> ValueType {
+        match *value {
+            Value::Null => ValueType::Null,
+            Value::Boolean(_) => ValueType::Boolean,
+            Value::I64(_) => ValueType::I64,
+            Value::U64(_) => ValueType::U64,
+            Value::F64(_) => ValueType::F64,
+            Value::String(_) => ValueType::String,
+            Value::File(_) => ValueType::File,
+            Value::Array(_) => ValueType::Array,
+            Value::Map(_) => ValueType::Map,
+        }
+    }
+
+    /// The name used to select a message for sized rules,
+    /// which is one of `string`, `numeric`, `array`, or `file`.
+    fn size_name(&self) -> Option<&'static str> {
+        match *self {
+            ValueType::String => Some("string"),
+            ValueType::I64 | ValueType::U64 | ValueType::F64 => Some("numeric"),
+            ValueType::Array | ValueType::Map => Some("array"),
+            ValueType::File => Some("file"),
+            ValueType::Null | ValueType::Boolean => None,
+        }
+    }
+}
+
+/// A parameter of the rule that produced a `ValidationError`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Param {
+    /// A single argument given to the rule, such as a size limit or a pattern.
+    Value(String),
+    /// A list of values given to the rule, such as the options for `In`.
+    Values(Vec<Value>),
+    /// Other fields referenced by the rule, as dotted paths.
+    Fields(Vec<String>),
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Param::Value(ref value) => f.write_str(value),
+            Param::Values(ref values) => write!(f, "{:?}", values),
+            Param::Fields(ref fields) => f.write_str(&fields.join(", ")),
+        }
+    }
+}
+
+/// A single failed rule for a field.
+///
+/// The `Display` implementation gives a human-readable English message,
+/// such as "The email field must contain a valid email address.".
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    kind: ErrorKind,
+    field: String,
+    params: BTreeMap<&'static str, Param>,
+    value_type: Option<ValueType>,
+    message: String,
+}
+
+impl ValidationError {
+    /// Creates an error of the given kind for the field at `field`.
+    pub fn new(kind: ErrorKind, field: &[&str]) -> ValidationError {
+        ValidationError {
+            kind,
+            field: field.join("."),
+            params: BTreeMap::new(),
+            value_type: None,
+            message: String::new(),
+        }
+    }
+
+    /// Adds a named parameter of the rule, which may be referenced
+    /// from the message as `:name`.
+    pub fn with_param(mut self, name: &'static str, param: Param) -> ValidationError {
+        self.params.insert(name, param);
+        self
+    }
+
+    /// The kind of rule that failed.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// A stable, machine-readable code for the rule that failed, such as `max`.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// The dotted path of the field that failed, such as `items.2.name`.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns the named parameter of the rule, such as `max` for the `Max` rule.
+    pub fn param(&self, name: &str) -> Option<&Param> {
+        self.params.get(name)
+    }
+
+    /// All parameters of the rule, by name.
+    pub fn params(&self) -> &BTreeMap<&'static str, Param> {
+        &self.params
+    }
+
+    /// The type of the value that failed, or `None` if the field was not present.
+    pub fn value_type(&self) -> Option<ValueType> {
+        self.value_type
+    }
+
+    /// The human-readable message for this error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The key of the message for this error, such as `max.string`.
+    pub fn message_key(&self) -> String {
+        match self.value_type.and_then(|value_type| value_type.size_name()) {
+            Some(size_name) if self.kind.is_sized() => format!("{}.{}", self.code(), size_name),
+            _ => self.code().to_owned(),
+        }
+    }
+
+    /// The number that the message should agree with, which is the size limit
+    /// of sized rules, the size of the other field for `Gt` and similar rules,
+    /// or the number of digits for `Digits` and `DigitsBetween`.
+    fn count(&self) -> Option<f64> {
+        ["size", "max", "min", "value", "digits"]
+            .iter()
+            .filter_map(|name| match self.params.get(name) {
+                Some(&Param::Value(ref value)) => value.parse::<f64>().ok(),
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Records the type of the offending value and renders the message
+    /// for the field, which was given in the rules as `pattern`.
+    pub(crate) fn finish(&mut self, value: Option<&Value>, pattern: &str, options: &Options) {
+        self.value_type = value.map(ValueType::of);
+        let message = {
+            let template = match options.custom_message(pattern, self) {
+                Some(custom) => custom.to_owned(),
+                None => options.template(&self.message_key(), self.count()),
+            };
+            messages::render(&template, self, options)
+        };
+        self.message = message;
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.message.is_empty() {
+            return f.write_str(&self.message);
+        }
+        // An error which has not been through `validate`, such as one returned
+        // by a `Validator`, has no message yet, so render the built-in English one
+        let options = Options::default();
+        let template = options.template(&self.message_key(), self.count());
+        f.write_str(&messages::render(&template, self, &options))
+    }
+}
+
+impl Error for ValidationError {}
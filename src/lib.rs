@@ -1,44 +1,92 @@
 #![cfg_attr(feature="clippy", feature(plugin))]
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
+extern crate chrono;
 extern crate dns_lookup;
+extern crate iron;
 #[macro_use]
 extern crate lazy_static;
 extern crate params;
+extern crate rand;
 #[cfg(feature = "pg")]
 extern crate postgres;
 extern crate regex;
 extern crate serde_json;
+extern crate unicode_segmentation;
 extern crate url;
 
+pub use error::{ErrorKind, Param, ValidationError, ValueType};
+pub use measure::{FileSize, Length, Number};
+pub use middleware::{Validate, Validated, ValidationFailed};
+pub use options::Options;
+pub use parse::ParseError;
+pub use resolve::{CachingResolver, RecordType, ResolveError, Resolver, StaticResolver,
+                  SystemResolver};
+pub use translate::{Catalog, CatalogError, Translator, plural_category};
+pub use validator::{Context, Validator};
+pub use validators::dimensions::Dimensions;
+pub use validators::email::{EmailMode, EmailOptions};
+pub use validators::mac_address::{MacAddressOptions, MacNotation};
+pub use validators::timezone::TimezoneOptions;
+pub use validators::url::UrlOptions;
+pub use validators::uuid::UuidOptions;
 use params::{Map, Value};
+use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::sync::Arc;
+use validator::Closure;
+
+mod error;
+mod measure;
+mod messages;
+mod middleware;
+mod options;
+mod parse;
+mod path;
+mod resolve;
+mod sniff;
+mod translate;
+mod validator;
 
 mod validators {
     pub mod accepted;
     pub mod active_url;
+    pub mod affix;
     pub mod alpha;
     pub mod alpha_dash;
     pub mod alpha_numeric;
     pub mod array;
+    pub mod ascii;
     pub mod between;
     pub mod boolean;
+    pub mod compare;
     pub mod confirmed;
+    pub mod date;
+    pub mod date_compare;
+    pub mod date_format;
+    pub mod decimal;
     pub mod different;
     pub mod digits;
     pub mod digits_between;
+    pub mod dimensions;
     pub mod distinct;
     pub mod email;
     #[cfg(feature = "pg")]
     pub mod exists;
+    pub mod file;
+    pub mod file_size;
     pub mod filled;
     pub mod in_const;
     pub mod in_array;
     pub mod integer;
     pub mod ip_address;
     pub mod json;
+    pub mod lowercase;
+    pub mod mac_address;
     pub mod max;
+    pub mod mimes;
     pub mod min;
+    pub mod multiple_of;
     pub mod not_in;
     pub mod not_in_array;
     pub mod numeric;
@@ -54,9 +102,13 @@ mod validators {
     pub mod same;
     pub mod size;
     pub mod string;
+    pub mod timezone;
+    pub mod ulid;
     #[cfg(feature = "pg")]
     pub mod unique;
+    pub mod uppercase;
     pub mod url;
+    pub mod uuid;
 }
 
 #[derive(Debug,Clone)]
@@ -66,8 +118,21 @@ pub enum Rule<'a> {
     ///
     /// On success, will transform the input to a boolean `true`.
     Accepted,
-    /// The field under validation, if present, must be an active domain name.
+    /// The field under validation, if present, must be a URL or domain name, such as
+    /// `https://example.com/path` or `example.com`, whose host has an `A` or `AAAA` record.
+    /// A host which is an IP address is always active.
+    ///
+    /// Records are looked up with the `Resolver` set by `Options::resolver`.
     ActiveUrl,
+    /// The field under validation, if present, must be a date after the given date.
+    ///
+    /// The given date may be a date, such as `2017-03-14`,
+    /// a relative date, such as `today`, `tomorrow`, or `+30 days`, or the name of another field.
+    /// Dates are parsed in the same way as the `Date` rule.
+    After(&'a str),
+    /// The field under validation, if present, must be a date after or equal to the given date.
+    /// The given date is interpreted in the same way as for the `After` rule.
+    AfterOrEqual(&'a str),
     /// The field under validation must be entirely alphabetic characters.
     ///
     /// This validator accepts Latin and international (Unicode) input.
@@ -83,9 +148,26 @@ pub enum Rule<'a> {
     AlphaNumeric,
     /// The field under validation, if present, must be an array.
     Array,
+    /// The field under validation, if present, must be a string made up entirely of
+    /// ASCII characters.
+    Ascii,
+    /// Stops running the rules for the field under validation after the first rule that fails.
+    ///
+    /// This is useful for skipping expensive rules, such as `Unique` or `ActiveUrl`,
+    /// when the value has already failed a cheaper rule. It applies to the whole ruleset
+    /// for the field, wherever it appears in the list.
+    Bail,
+    /// The field under validation, if present, must be a date before the given date.
+    /// The given date is interpreted in the same way as for the `After` rule.
+    Before(&'a str),
+    /// The field under validation, if present, must be a date before or equal to the given date.
+    /// The given date is interpreted in the same way as for the `After` rule.
+    BeforeOrEqual(&'a str),
     /// The field under validation, if present, must have a size between the given min and max.
     /// Strings, numerics, and files are evaluated in the same fashion as the `Size` rule.
-    Between(isize, isize),
+    ///
+    /// Bounds may be integers or floats, as in `Rule::Between(0.into(), 99.99.into())`.
+    Between(Number, Number),
     /// The field under validation, if present, must be able to be cast as a boolean.
     /// Accepted input are `true`, `false`, `1`, `0`, `"1"`, and `"0"`.
     ///
@@ -95,8 +177,36 @@ pub enum Rule<'a> {
     /// For example, if the field under validation is `password`,
     /// a matching `password_confirmation` field must be present in the input.
     Confirmed,
+    /// The field under validation must pass a user-defined `Validator`.
+    ///
+    /// Use `Rule::custom` or `Rule::closure` to create this rule.
+    Custom(Arc<dyn Validator + 'a>),
+    /// The field under validation, if present, must be a valid ISO 8601 or RFC 3339 date,
+    /// such as `2017-03-14`, `2017-03-14T15:09:26`, or `2017-03-14T15:09:26+01:00`.
+    ///
+    /// On success, will transform the input to an ISO 8601 string, such as `2017-03-14`
+    /// for dates or `2017-03-14T15:09:26` for date times. Date times with an offset
+    /// are converted to UTC, such as `2017-03-14T14:09:26Z`.
+    Date,
+    /// The field under validation, if present, must be a date equal to the given date.
+    /// The given date is interpreted in the same way as for the `After` rule.
+    DateEquals(&'a str),
+    /// The field under validation, if present, must match the given strftime-style format,
+    /// such as `%d/%m/%Y`.
+    ///
+    /// On success, will transform the input to an ISO 8601 string, as with the `Date` rule,
+    /// or to a time such as `15:09:26` if the format only contains a time.
+    DateFormat(&'a str),
+    /// The field under validation, if present, must be a number with between the given
+    /// minimum and maximum number of decimal places, inclusive, such as `Decimal(2, 2)`
+    /// for a price.
+    ///
+    /// Places are counted in the string as given, trailing zeros included, so `10.50`
+    /// has two places. Run this rule before `Numeric`, which transforms the string
+    /// to a float and drops the trailing zeros.
+    Decimal(usize, usize),
     /// The field under validation must have a different value than `field`.
-    Different(&'static str),
+    Different(&'a str),
     /// The field under validation, if present,
     /// must be numeric and must have an exact length of value.
     ///
@@ -110,21 +220,63 @@ pub enum Rule<'a> {
     ///
     /// On success, will transform string input to a numeric type.
     DigitsBetween(usize, usize),
+    /// The field under validation, if present, must be an uploaded PNG, JPEG, GIF, WebP,
+    /// or BMP image which meets the given `Dimensions`, such as a minimum width or a ratio.
+    ///
+    /// The size of the image is read from its headers. When a constraint is not met,
+    /// the error has a `constraint` parameter naming it, such as `min_width`,
+    /// along with the constraint's value and the `width` and `height` of the image.
+    Dimensions(Dimensions),
     /// When working with arrays, the field under validation must not have any duplicate values
     /// if it is present.
     Distinct,
+    /// The field under validation, if present, must be a string which does not end
+    /// with any of the given values.
+    DoesntEndWith(Vec<&'a str>),
+    /// The field under validation, if present, must be a string which does not start
+    /// with any of the given values.
+    DoesntStartWith(Vec<&'a str>),
     /// The field under validation, if present, must be formatted as an e-mail address.
-    Email,
+    ///
+    /// By default, addresses are checked against the `addr-spec` of RFC 5322, so
+    /// `a@b.c d` and `foo@bar.com,baz@qux.com` fail. `EmailOptions` may check against
+    /// the HTML standard instead, reject quoted local parts or IP address domains,
+    /// and transform the domain to lowercase.
+    ///
+    /// On success, will transform an internationalized domain to punycode,
+    /// as in `user@xn--mnchen-3ya.de`.
+    Email(EmailOptions),
+    /// The field under validation, if present, must be a string which ends
+    /// with one of the given values.
+    EndsWith(Vec<&'a str>),
     #[cfg(feature = "pg")]
     /// The field under validation must exist in the given table.
     /// A column name may be specified; otherwise, the name of the field is used.
     Exists(&'a postgres::Connection, &'static str, Option<&'static str>),
+    /// The field under validation, if present, must be an uploaded file.
+    File,
     /// The field under validation must not be empty when it is present.
     Filled,
+    /// The field under validation, if present, must be greater than the given field.
+    /// Both fields must be the same kind of value, which are compared
+    /// in the same fashion as the `Size` rule, except that when the field under validation
+    /// is a number, a numeric string in the given field is compared as a number.
+    Gt(&'a str),
+    /// The field under validation, if present, must be greater than or equal to
+    /// the given field, which is compared in the same fashion as the `Gt` rule.
+    Gte(&'a str),
+    /// The field under validation, if present, must be an uploaded image:
+    /// a JPEG, PNG, GIF, BMP, or WebP file.
+    ///
+    /// As in Laravel, SVG images are not accepted, since they may contain scripts.
+    /// To accept them, use `MimeTypes` with `image/svg+xml` instead.
+    ///
+    /// This is checked in the same fashion as the `MimeTypes` rule.
+    Image,
     /// The field under validation, if present, must be included in the given list of values.
     In(Vec<Value>),
     /// The field under validation, if present, must exist in `anotherfield`'s values.
-    InArray(&'static str),
+    InArray(&'a str),
     /// The field under validation, if present, must be an integer.
     ///
     /// On success, will transform string input to a numeric type.
@@ -135,16 +287,75 @@ pub enum Rule<'a> {
     IpAddress,
     /// The field under validation, if present, must be a valid JSON string.
     Json,
+    /// Sets how the length of a string is counted by the `Size` family of rules,
+    /// and by `Gt`, `Gte`, `Lt`, and `Lte`, for the field under validation.
+    ///
+    /// This overrides the length set with `Options::length`. Like `Bail`, it applies to
+    /// the whole ruleset for the field, wherever it appears in the list.
+    Length(Length),
+    /// The field under validation, if present, must be a string which is entirely lowercase,
+    /// such that converting it to lowercase with the Unicode case mappings leaves it unchanged.
+    ///
+    /// Characters without case, such as digits and punctuation, are allowed.
+    Lowercase,
+    /// The field under validation, if present, must be less than the given field,
+    /// which is compared in the same fashion as the `Gt` rule.
+    Lt(&'a str),
+    /// The field under validation, if present, must be less than or equal to
+    /// the given field, which is compared in the same fashion as the `Gt` rule.
+    Lte(&'a str),
+    /// The field under validation, if present, must be an EUI-48 or EUI-64 MAC address,
+    /// in colon, hyphen, Cisco dot, or bare hexadecimal notation, as in `00:1a:2b:3c:4d:5e`,
+    /// `00-1A-2B-3C-4D-5E`, `001a.2b3c.4d5e`, or `001a2b3c4d5e`.
+    ///
+    /// `MacAddressOptions` may also reject multicast or locally administered addresses,
+    /// and transform valid addresses to a single `MacNotation`.
+    MacAddress(MacAddressOptions),
     /// The field under validation, if present, must be less than or equal to a maximum value.
     /// Strings, numerics, and files are evaluated in the same fashion as the `Size` rule.
-    Max(isize),
+    Max(Number),
+    /// The field under validation, if present, must be an uploaded file
+    /// no larger than the given size, which is compared exactly in bytes.
+    ///
+    /// The size may be written with a unit, as in `Rule::MaxFileSize("2.5MB".parse().unwrap())`,
+    /// and is shown in error messages in that unit.
+    MaxFileSize(FileSize),
+    /// The field under validation, if present, must be an uploaded file
+    /// with one of the given MIME types, such as `image/png`.
+    /// A type may end in a wildcard to accept any subtype, as in `image/*`.
+    ///
+    /// The type declared by the client must be one of the given types, and the contents
+    /// of the file must agree with the declared type, based on the magic bytes at its start.
+    /// A file whose contents do not agree fails with `ErrorKind::MimeMismatch`.
+    MimeTypes(Vec<&'a str>),
+    /// The field under validation, if present, must be an uploaded file
+    /// with one of the given extensions, such as `pdf` or `docx`.
+    ///
+    /// The extension is taken from the file name given by the client, and the contents
+    /// of the file must agree with the extension, based on the magic bytes at its start.
+    /// A file whose contents do not agree fails with `ErrorKind::MimeMismatch`.
+    Mimes(Vec<&'a str>),
     /// The field under validation, if present, must have a minimum value.
     /// Strings, numerics, and files are evaluated in the same fashion as the `Size` rule.
-    Min(isize),
+    Min(Number),
+    /// The field under validation, if present, must be an uploaded file
+    /// at least as large as the given size, in the same fashion as the `MaxFileSize` rule.
+    MinFileSize(FileSize),
+    /// The field under validation, if present, must be a multiple of the given number,
+    /// such as `MultipleOf(0.05.into())` for prices in steps of five cents.
+    ///
+    /// Numbers are divided exactly as decimals, so `0.3` is a multiple of `0.1`.
+    /// Strings are read as numbers, but are not transformed.
+    MultipleOf(Number),
     /// The field under validation must not be included in the given list of values.
     NotIn(Vec<Value>),
     /// The field under validation must not exist in `anotherfield`'s values.
-    NotInArray(&'static str),
+    NotInArray(&'a str),
+    /// The field under validation may be null or absent, in which case
+    /// every other rule for the field passes.
+    ///
+    /// Otherwise, the other rules for the field are run as usual.
+    Nullable,
     /// The field under validation, if present, must be numeric.
     ///
     /// On success, will transform string input to a numeric type.
@@ -153,8 +364,11 @@ pub enum Rule<'a> {
     Present,
     /// The field under validation, if present, must match the given regular expression.
     ///
+    /// The pattern is given in the syntax of the `regex` crate, without delimiters,
+    /// as in `Rule::Regex(r"^\d+$".into())`.
+    ///
     /// On success, will transform input to a string.
-    Regex(&'static str),
+    Regex(Cow<'a, str>),
     /// The field under validation must be present in the input data and not empty.
     /// A field is considered "empty" if one of the following conditions are true:
     ///
@@ -166,232 +380,151 @@ pub enum Rule<'a> {
     Required,
     /// The field under validation must be present if the `anotherfield` field
     /// is equal to any `value`.
-    RequiredIf(&'static str, Value),
+    RequiredIf(&'a str, Vec<Value>),
     /// The field under validation must be present unless the `anotherfield` field
     /// is equal to any `value`.
-    RequiredUnless(&'static str, Value),
+    RequiredUnless(&'a str, Vec<Value>),
     /// The field under validation must be present only if
     /// any of the other specified fields are present.
-    RequiredWith(Vec<&'static str>),
+    RequiredWith(Vec<&'a str>),
     /// The field under validation must be present only if
     /// all of the other specified fields are present.
-    RequiredWithAll(Vec<&'static str>),
+    RequiredWithAll(Vec<&'a str>),
     /// The field under validation must be present only when
     /// any of the other specified fields are not present.
-    RequiredWithout(Vec<&'static str>),
+    RequiredWithout(Vec<&'a str>),
     /// The field under validation must be present only when
     /// all of the other specified fields are not present.
-    RequiredWithoutAll(Vec<&'static str>),
+    RequiredWithoutAll(Vec<&'a str>),
     /// The given field must match the field under validation.
-    Same(&'static str),
+    Same(&'a str),
     /// The field under validation must have a size matching the given value.
     ///
-    /// For string data, value corresponds to the number of characters.
+    /// For string data, value corresponds to the number of characters,
+    /// which are Unicode scalar values unless counted otherwise with the `Length` rule.
+    ///
+    /// For numeric data, value corresponds to the given number.
     ///
-    /// For numeric data, value corresponds to a given integer value.
+    /// For files, size corresponds to the file size in kilobytes, compared exactly,
+    /// so that a file of 1.5 kilobytes is larger than `1` and smaller than `2`.
+    /// Use `MaxFileSize` or `MinFileSize` to give a limit in other units.
     ///
-    /// For files, size corresponds to the file size in kilobytes.
-    Size(isize),
+    /// A size which cannot be matched, such as a negative length, fails validation.
+    Size(Number),
+    /// The rules for the field under validation are only run if the field is present
+    /// in the input data, even if it is null or empty.
+    Sometimes,
+    /// The field under validation, if present, must be a string which starts
+    /// with one of the given values.
+    StartsWith(Vec<&'a str>),
     /// The field under validation, if present, must be a string.
     String,
+    /// The field under validation, if present, must be a valid IANA time zone name,
+    /// such as `America/New_York`, including links to other zones, such as `US/Eastern`.
+    ///
+    /// `TimezoneOptions` may also allow fixed offsets from UTC, such as `+05:30`,
+    /// and transform links to the name of the zone they refer to.
+    Timezone(TimezoneOptions),
+    /// The field under validation, if present, must be a ULID, such as
+    /// `01ARZ3NDEKTSV4RRFFQ69G5FAV`, written in Crockford's base32 in either case,
+    /// whose timestamp fits in 48 bits.
+    ///
+    /// On success, will transform the input to the canonical uppercase form.
+    Ulid,
     #[cfg(feature = "pg")]
     /// The field under validation must not exist in the given table.
     /// A column name may be specified; otherwise, the name of the field is used.
     Unique(&'a postgres::Connection, &'static str, Option<&'static str>),
+    /// The field under validation, if present, must be a string which is entirely uppercase,
+    /// such that converting it to uppercase with the Unicode case mappings leaves it unchanged.
+    ///
+    /// Characters without case, such as digits and punctuation, are allowed.
+    Uppercase,
     /// The field under validation, if present, must be formatted as a valid URL,
     /// but does not need to resolve to a real website. The URL must contain the scheme
     /// or else it will fail validation. For example, `http://google.com` will
     /// pass validation, but `google.com` will fail validation.
-    Url,
+    ///
+    /// `UrlOptions` may restrict the schemes which are accepted, such as to `http`
+    /// and `https`, require a host, reject credentials, IP addresses, or private hosts,
+    /// and limit the length of the URL. They may also transform valid URLs to their
+    /// normalized form.
+    Url(UrlOptions<'a>),
+    /// The field under validation, if present, must be a UUID, such as
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    ///
+    /// `UuidOptions` may restrict the versions of UUID which are accepted,
+    /// and whether only the canonical lowercase, hyphenated form is accepted.
+    /// Otherwise, on success, will transform the input to the canonical form.
+    Uuid(UuidOptions),
     #[doc(hidden)]
     #[cfg(not(feature = "pg"))]
     Phantom(&'a std::marker::PhantomData<u8>),
 }
 
+impl<'a> Rule<'a> {
+    /// Creates a rule which validates the field with a user-defined `Validator`.
+    pub fn custom<V: Validator + 'a>(validator: V) -> Rule<'a> {
+        Rule::Custom(Arc::new(validator))
+    }
+
+    /// Creates a rule which validates the field with a closure,
+    /// which is called in the same way as `Validator::validate`.
+    ///
+    /// ```
+    /// # extern crate iron_valid;
+    /// # extern crate params;
+    /// use iron_valid::{ErrorKind, Rule, ValidationError};
+    /// use params::Value;
+    ///
+    /// # fn main() {
+    /// let sku = Rule::closure(|ctx, path| match ctx.find(path) {
+    ///     Some(&Value::String(ref sku)) if !sku.starts_with("SKU-") => {
+    ///         Err(ValidationError::new(ErrorKind::Custom("sku"), path))
+    ///     }
+    ///     _ => Ok(None),
+    /// });
+    /// # }
+    /// ```
+    pub fn closure<F>(validator: F) -> Rule<'a>
+        where F: Fn(&Context, &[&str]) -> Result<Option<Value>, ValidationError> + Send + Sync + 'a
+    {
+        Rule::Custom(Arc::new(Closure(validator)))
+    }
+}
+
 /// Validate a map of `values` against a map of `rules`.
 ///
 /// Returns a `Result` containing a map of post-processed `values`,
-/// or a map of validation error messages.
-pub fn validate(rules: &BTreeMap<&'static str, Vec<Rule>>,
+/// or a map of `ValidationError`s for each field that failed.
+///
+/// A `*` segment in a field name matches every element of an array
+/// (or every key of a map), so `items.*.name` validates the name of each item.
+/// Errors are reported under the concrete path, such as `items.2.name`,
+/// and any `*` in another field passed to a `Rule` is resolved to the same index.
+pub fn validate(rules: &BTreeMap<&str, Vec<Rule>>,
                 values: Map)
-                -> Result<Map, BTreeMap<&'static str, Vec<String>>> {
+                -> Result<Map, BTreeMap<String, Vec<ValidationError>>> {
+    validate_with(rules, values, &Options::new())
+}
+
+/// Validate a map of `values` against a map of `rules`, as with `validate`,
+/// using the given `options`.
+pub fn validate_with(rules: &BTreeMap<&str, Vec<Rule>>,
+                     values: Map,
+                     options: &Options)
+                     -> Result<Map, BTreeMap<String, Vec<ValidationError>>> {
     let mut new_values = values;
     let mut errors = BTreeMap::new();
 
     for (field, ruleset) in rules {
-        let array_field = field.split('.').collect::<Vec<&str>>();
-        let mut current_errors = Vec::new();
-        for rule in ruleset {
-            let result = match *rule {
-                Rule::Accepted => {
-                    validators::accepted::validate_accepted(&new_values, &array_field)
-                }
-                Rule::ActiveUrl => {
-                    validators::active_url::validate_active_url(&new_values, &array_field)
-                }
-                Rule::Alpha => validators::alpha::validate_alpha(&new_values, &array_field),
-                Rule::AlphaDash => {
-                    validators::alpha_dash::validate_alpha_dash(&new_values, &array_field)
-                }
-                Rule::AlphaNumeric => {
-                    validators::alpha_numeric::validate_alpha_numeric(&new_values, &array_field)
-                }
-                Rule::Array => validators::array::validate_array(&new_values, &array_field),
-                Rule::Between(min, max) => {
-                    validators::between::validate_between(&new_values, &array_field, min, max)
-                }
-                Rule::Boolean => validators::boolean::validate_boolean(&new_values, &array_field),
-                Rule::Confirmed => {
-                    validators::confirmed::validate_confirmed(&new_values, &array_field)
-                }
-                Rule::Different(other) => {
-                    let other = other.split('.').collect::<Vec<&str>>();
-                    validators::different::validate_different(&new_values, &array_field, &other)
-                }
-                Rule::Digits(digits) => {
-                    validators::digits::validate_digits(&new_values, &array_field, digits)
-                }
-                Rule::DigitsBetween(min, max) => {
-                    validators::digits_between::validate_digits_between(&new_values,
-                                                                        &array_field,
-                                                                        min,
-                                                                        max)
-                }
-                Rule::Distinct => {
-                    validators::distinct::validate_distinct(&new_values, &array_field)
-                }
-                Rule::Email => validators::email::validate_email(&new_values, &array_field),
-                #[cfg(feature = "pg")]
-                Rule::Exists(conn, table, column) => {
-                    validators::exists::validate_exists(conn,
-                                                        &new_values,
-                                                        &array_field,
-                                                        table,
-                                                        column)
-                }
-                Rule::Filled => validators::filled::validate_filled(&new_values, &array_field),
-                Rule::In(ref options) => {
-                    validators::in_const::validate_in(&new_values, &array_field, options)
-                }
-                Rule::InArray(other) => {
-                    let other = other.split('.').collect::<Vec<&str>>();
-                    validators::in_array::validate_in_array(&new_values, &array_field, &other)
-                }
-                Rule::Integer => validators::integer::validate_integer(&new_values, &array_field),
-                Rule::IpAddress => {
-                    validators::ip_address::validate_ip_address(&new_values, &array_field)
-                }
-                Rule::Json => validators::json::validate_json(&new_values, &array_field),
-                Rule::Max(target) => {
-                    validators::max::validate_max(&new_values, &array_field, target)
-                }
-                Rule::Min(target) => {
-                    validators::min::validate_min(&new_values, &array_field, target)
-                }
-                Rule::NotIn(ref options) => {
-                    validators::not_in::validate_not_in(&new_values, &array_field, options)
-                }
-                Rule::NotInArray(other) => {
-                    let other = other.split('.').collect::<Vec<&str>>();
-                    validators::not_in_array::validate_not_in_array(&new_values,
-                                                                    &array_field,
-                                                                    &other)
-                }
-                Rule::Numeric => validators::numeric::validate_numeric(&new_values, &array_field),
-                Rule::Present => validators::present::validate_present(&new_values, &array_field),
-                Rule::Regex(pattern) => {
-                    validators::regex::validate_regex(&new_values, &array_field, pattern)
-                }
-                Rule::Required => {
-                    validators::required::validate_required(&new_values, &array_field)
-                }
-                Rule::RequiredIf(other, ref condition) => {
-                    let other = other.split('.').collect::<Vec<&str>>();
-                    validators::required_if::validate_required_if(&new_values,
-                                                                  &array_field,
-                                                                  &other,
-                                                                  condition)
-                }
-                Rule::RequiredUnless(other, ref condition) => {
-                    let other = other.split('.').collect::<Vec<&str>>();
-                    validators::required_unless::validate_required_unless(&new_values,
-                                                                          &array_field,
-                                                                          &other,
-                                                                          condition)
-                }
-                Rule::RequiredWith(ref others) => {
-                    let others = others.iter()
-                        .map(|o| o.split('.').collect::<Vec<&str>>())
-                        .collect::<Vec<Vec<&str>>>();
-                    validators::required_with::validate_required_with(&new_values,
-                                                                      &array_field,
-                                                                      &others)
-                }
-                Rule::RequiredWithAll(ref others) => {
-                    let others = others.iter()
-                        .map(|o| o.split('.').collect::<Vec<&str>>())
-                        .collect::<Vec<Vec<&str>>>();
-                    validators::required_with_all::validate_required_with_all(&new_values,
-                                                                              &array_field,
-                                                                              &others)
-                }
-                Rule::RequiredWithout(ref others) => {
-                    let others = others.iter()
-                        .map(|o| o.split('.').collect::<Vec<&str>>())
-                        .collect::<Vec<Vec<&str>>>();
-                    validators::required_without::validate_required_without(&new_values,
-                                                                            &array_field,
-                                                                            &others)
-                }
-                Rule::RequiredWithoutAll(ref others) => {
-                    let others = others.iter()
-                        .map(|o| o.split('.').collect::<Vec<&str>>())
-                        .collect::<Vec<Vec<&str>>>();
-                    validators::required_without_all::validate_required_without_all(&new_values,
-                                                                                    &array_field,
-                                                                                    &others)
-                }
-                Rule::Same(other) => {
-                    let other = other.split('.').collect::<Vec<&str>>();
-                    validators::same::validate_same(&new_values, &array_field, &other)
-                }
-                Rule::Size(target) => {
-                    validators::size::validate_size(&new_values, &array_field, target)
-                }
-                Rule::String => validators::string::validate_string(&new_values, &array_field),
-                #[cfg(feature = "pg")]
-                Rule::Unique(conn, table, column) => {
-                    validators::unique::validate_unique(conn,
-                                                        &new_values,
-                                                        &array_field,
-                                                        table,
-                                                        column)
-                }
-                Rule::Url => validators::url::validate_url(&new_values, &array_field),
-                #[cfg(not(feature = "pg"))]
-                Rule::Phantom(_) => unimplemented!(),
-            };
-            match result {
-                Ok(Some(res)) => {
-                    new_values.assign(&array_field.iter()
-                                           .enumerate()
-                                           .map(|(i, x)| if i == 0 {
-                                                    x.to_string()
-                                                } else {
-                                                    format!("[{}]", x)
-                                                })
-                                           .collect::<String>(),
-                                      res)
-                        .ok();
-                }
-                Ok(None) => (),
-                Err(err) => {
-                    current_errors.push(err);
-                }
-            };
-        }
-        if !current_errors.is_empty() {
-            errors.insert(*field, current_errors);
+        let pattern = field.split('.').collect::<Vec<&str>>();
+        for concrete in path::expand(&new_values, &pattern) {
+            let current_errors =
+                validate_field(&mut new_values, ruleset, &pattern, &concrete, options);
+            if !current_errors.is_empty() {
+                errors.insert(concrete.join("."), current_errors);
+            }
         }
     }
 
@@ -401,3 +534,338 @@ pub fn validate(rules: &BTreeMap<&'static str, Vec<Rule>>,
         Err(errors)
     }
 }
+
+/// Runs each rule in `ruleset` against the field at the `concrete` path,
+/// which was expanded from `pattern`.
+fn validate_field(new_values: &mut Map,
+                  ruleset: &[Rule],
+                  pattern: &[&str],
+                  concrete: &[String],
+                  options: &Options)
+                  -> Vec<ValidationError> {
+    let array_field = path::segments(concrete);
+    let nullable = ruleset.iter().any(|rule| matches!(*rule, Rule::Nullable));
+    let sometimes = ruleset.iter().any(|rule| matches!(*rule, Rule::Sometimes));
+    match path::find(new_values, &array_field) {
+        None if nullable || sometimes => return Vec::new(),
+        Some(&Value::Null) if nullable => return Vec::new(),
+        _ => (),
+    }
+    let bail = options.bails() || ruleset.iter().any(|rule| matches!(*rule, Rule::Bail));
+    let length = ruleset.iter()
+        .rev()
+        .find_map(|rule| match *rule {
+            Rule::Length(length) => Some(length),
+            _ => None,
+        })
+        .unwrap_or_else(|| options.string_length());
+    let mut current_errors = Vec::new();
+    for rule in ruleset {
+        let result = match *rule {
+            Rule::Accepted => {
+                validators::accepted::validate_accepted(new_values, &array_field)
+            }
+            Rule::ActiveUrl => {
+                validators::active_url::validate_active_url(new_values,
+                                                            &array_field,
+                                                            options.dns_resolver())
+            }
+            Rule::After(target) => {
+                let other = path::resolve(target, pattern, concrete);
+                let other = path::segments(&other);
+                validators::date_compare::validate_after(new_values, &array_field, target, &other)
+            }
+            Rule::AfterOrEqual(target) => {
+                let other = path::resolve(target, pattern, concrete);
+                let other = path::segments(&other);
+                validators::date_compare::validate_after_or_equal(new_values,
+                                                                  &array_field,
+                                                                  target,
+                                                                  &other)
+            }
+            Rule::Alpha => validators::alpha::validate_alpha(new_values, &array_field),
+            Rule::AlphaDash => {
+                validators::alpha_dash::validate_alpha_dash(new_values, &array_field)
+            }
+            Rule::AlphaNumeric => {
+                validators::alpha_numeric::validate_alpha_numeric(new_values, &array_field)
+            }
+            Rule::Array => validators::array::validate_array(new_values, &array_field),
+            Rule::Ascii => validators::ascii::validate_ascii(new_values, &array_field),
+            Rule::Bail => Ok(None),
+            Rule::Before(target) => {
+                let other = path::resolve(target, pattern, concrete);
+                let other = path::segments(&other);
+                validators::date_compare::validate_before(new_values, &array_field, target, &other)
+            }
+            Rule::BeforeOrEqual(target) => {
+                let other = path::resolve(target, pattern, concrete);
+                let other = path::segments(&other);
+                validators::date_compare::validate_before_or_equal(new_values,
+                                                                   &array_field,
+                                                                   target,
+                                                                   &other)
+            }
+            Rule::Between(min, max) => {
+                validators::between::validate_between(new_values, &array_field, min, max, length)
+            }
+            Rule::Boolean => validators::boolean::validate_boolean(new_values, &array_field),
+            Rule::Confirmed => {
+                validators::confirmed::validate_confirmed(new_values, &array_field)
+            }
+            Rule::Custom(ref validator) => {
+                let ctx = Context::new(new_values, pattern, concrete);
+                validator.validate(&ctx, &array_field)
+            }
+            Rule::Date => validators::date::validate_date(new_values, &array_field),
+            Rule::DateEquals(target) => {
+                let other = path::resolve(target, pattern, concrete);
+                let other = path::segments(&other);
+                validators::date_compare::validate_date_equals(new_values,
+                                                               &array_field,
+                                                               target,
+                                                               &other)
+            }
+            Rule::DateFormat(format) => {
+                validators::date_format::validate_date_format(new_values, &array_field, format)
+            }
+            Rule::Decimal(min, max) => {
+                validators::decimal::validate_decimal(new_values, &array_field, min, max)
+            }
+            Rule::Different(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::different::validate_different(new_values, &array_field, &other)
+            }
+            Rule::Digits(digits) => {
+                validators::digits::validate_digits(new_values, &array_field, digits)
+            }
+            Rule::DigitsBetween(min, max) => {
+                validators::digits_between::validate_digits_between(new_values,
+                                                                    &array_field,
+                                                                    min,
+                                                                    max)
+            }
+            Rule::Dimensions(dimensions) => {
+                validators::dimensions::validate_dimensions(new_values, &array_field, dimensions)
+            }
+            Rule::Distinct => {
+                validators::distinct::validate_distinct(new_values, &array_field)
+            }
+            Rule::DoesntEndWith(ref suffixes) => {
+                validators::affix::validate_doesnt_end_with(new_values, &array_field, suffixes)
+            }
+            Rule::DoesntStartWith(ref prefixes) => {
+                validators::affix::validate_doesnt_start_with(new_values, &array_field, prefixes)
+            }
+            Rule::Email(email) => {
+                validators::email::validate_email(new_values,
+                                                  &array_field,
+                                                  email,
+                                                  options.dns_resolver())
+            }
+            Rule::EndsWith(ref suffixes) => {
+                validators::affix::validate_ends_with(new_values, &array_field, suffixes)
+            }
+            #[cfg(feature = "pg")]
+            Rule::Exists(conn, table, column) => {
+                validators::exists::validate_exists(conn,
+                                                    new_values,
+                                                    &array_field,
+                                                    table,
+                                                    column)
+            }
+            Rule::File => validators::file::validate_file(new_values, &array_field),
+            Rule::Filled => validators::filled::validate_filled(new_values, &array_field),
+            Rule::Gt(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::compare::validate_gt(new_values, &array_field, &other, length)
+            }
+            Rule::Gte(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::compare::validate_gte(new_values, &array_field, &other, length)
+            }
+            Rule::Image => validators::mimes::validate_image(new_values, &array_field),
+            Rule::In(ref options) => {
+                validators::in_const::validate_in(new_values, &array_field, options)
+            }
+            Rule::InArray(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::in_array::validate_in_array(new_values, &array_field, &other)
+            }
+            Rule::Integer => validators::integer::validate_integer(new_values, &array_field),
+            Rule::IpAddress => {
+                validators::ip_address::validate_ip_address(new_values, &array_field)
+            }
+            Rule::Json => validators::json::validate_json(new_values, &array_field),
+            Rule::Length(_) => Ok(None),
+            Rule::Lowercase => {
+                validators::lowercase::validate_lowercase(new_values, &array_field)
+            }
+            Rule::Lt(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::compare::validate_lt(new_values, &array_field, &other, length)
+            }
+            Rule::Lte(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::compare::validate_lte(new_values, &array_field, &other, length)
+            }
+            Rule::MacAddress(options) => {
+                validators::mac_address::validate_mac_address(new_values, &array_field, options)
+            }
+            Rule::Max(target) => {
+                validators::max::validate_max(new_values, &array_field, target, length)
+            }
+            Rule::MaxFileSize(size) => {
+                validators::file_size::validate_max_file_size(new_values, &array_field, size)
+            }
+            Rule::MimeTypes(ref types) => {
+                validators::mimes::validate_mime_types(new_values, &array_field, types)
+            }
+            Rule::Mimes(ref extensions) => {
+                validators::mimes::validate_mimes(new_values, &array_field, extensions)
+            }
+            Rule::Min(target) => {
+                validators::min::validate_min(new_values, &array_field, target, length)
+            }
+            Rule::MinFileSize(size) => {
+                validators::file_size::validate_min_file_size(new_values, &array_field, size)
+            }
+            Rule::MultipleOf(step) => {
+                validators::multiple_of::validate_multiple_of(new_values, &array_field, step)
+            }
+            Rule::NotIn(ref options) => {
+                validators::not_in::validate_not_in(new_values, &array_field, options)
+            }
+            Rule::NotInArray(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::not_in_array::validate_not_in_array(new_values,
+                                                                &array_field,
+                                                                &other)
+            }
+            Rule::Nullable => Ok(None),
+            Rule::Numeric => validators::numeric::validate_numeric(new_values, &array_field),
+            Rule::Present => validators::present::validate_present(new_values, &array_field),
+            Rule::Regex(ref pattern) => {
+                validators::regex::validate_regex(new_values, &array_field, pattern)
+            }
+            Rule::Required => {
+                validators::required::validate_required(new_values, &array_field)
+            }
+            Rule::RequiredIf(other, ref conditions) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::required_if::validate_required_if(new_values,
+                                                              &array_field,
+                                                              &other,
+                                                              conditions)
+            }
+            Rule::RequiredUnless(other, ref conditions) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::required_unless::validate_required_unless(new_values,
+                                                                      &array_field,
+                                                                      &other,
+                                                                      conditions)
+            }
+            Rule::RequiredWith(ref others) => {
+                let others = others.iter()
+                    .map(|o| path::resolve(o, pattern, concrete))
+                    .collect::<Vec<Vec<String>>>();
+                let others = others.iter()
+                    .map(|o| path::segments(o))
+                    .collect::<Vec<Vec<&str>>>();
+                validators::required_with::validate_required_with(new_values,
+                                                                  &array_field,
+                                                                  &others)
+            }
+            Rule::RequiredWithAll(ref others) => {
+                let others = others.iter()
+                    .map(|o| path::resolve(o, pattern, concrete))
+                    .collect::<Vec<Vec<String>>>();
+                let others = others.iter()
+                    .map(|o| path::segments(o))
+                    .collect::<Vec<Vec<&str>>>();
+                validators::required_with_all::validate_required_with_all(new_values,
+                                                                          &array_field,
+                                                                          &others)
+            }
+            Rule::RequiredWithout(ref others) => {
+                let others = others.iter()
+                    .map(|o| path::resolve(o, pattern, concrete))
+                    .collect::<Vec<Vec<String>>>();
+                let others = others.iter()
+                    .map(|o| path::segments(o))
+                    .collect::<Vec<Vec<&str>>>();
+                validators::required_without::validate_required_without(new_values,
+                                                                        &array_field,
+                                                                        &others)
+            }
+            Rule::RequiredWithoutAll(ref others) => {
+                let others = others.iter()
+                    .map(|o| path::resolve(o, pattern, concrete))
+                    .collect::<Vec<Vec<String>>>();
+                let others = others.iter()
+                    .map(|o| path::segments(o))
+                    .collect::<Vec<Vec<&str>>>();
+                validators::required_without_all::validate_required_without_all(new_values,
+                                                                                &array_field,
+                                                                                &others)
+            }
+            Rule::Same(other) => {
+                let other = path::resolve(other, pattern, concrete);
+                let other = path::segments(&other);
+                validators::same::validate_same(new_values, &array_field, &other)
+            }
+            Rule::Size(target) => {
+                validators::size::validate_size(new_values, &array_field, target, length)
+            }
+            Rule::Sometimes => Ok(None),
+            Rule::StartsWith(ref prefixes) => {
+                validators::affix::validate_starts_with(new_values, &array_field, prefixes)
+            }
+            Rule::String => validators::string::validate_string(new_values, &array_field),
+            Rule::Timezone(timezone) => {
+                validators::timezone::validate_timezone(new_values, &array_field, timezone)
+            }
+            Rule::Ulid => validators::ulid::validate_ulid(new_values, &array_field),
+            #[cfg(feature = "pg")]
+            Rule::Unique(conn, table, column) => {
+                validators::unique::validate_unique(conn,
+                                                    new_values,
+                                                    &array_field,
+                                                    table,
+                                                    column)
+            }
+            Rule::Uppercase => {
+                validators::uppercase::validate_uppercase(new_values, &array_field)
+            }
+            Rule::Url(ref url) => validators::url::validate_url(new_values, &array_field, url),
+            Rule::Uuid(options) => {
+                validators::uuid::validate_uuid(new_values, &array_field, options)
+            }
+            #[cfg(not(feature = "pg"))]
+            Rule::Phantom(_) => unimplemented!(),
+        };
+        match result {
+            Ok(Some(res)) => {
+                path::assign(new_values, &array_field, res);
+            }
+            Ok(None) => (),
+            Err(mut err) => {
+                err.finish(path::find(new_values, &array_field), &pattern.join("."), options);
+                current_errors.push(err);
+                if bail {
+                    break;
+                }
+            }
+        };
+    }
+    current_errors
+}
@@ -0,0 +1,385 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use params::Value;
+use parse::ParseError;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A number, such as the bound given to `Max`, which may be an integer or a float.
+///
+/// Numbers are compared exactly, regardless of their representation,
+/// so `Number::Unsigned(5)` is equal to `Number::Float(5.0)`,
+/// and a `u64` above `i64::MAX` is compared correctly with any other number.
+/// Integers and floats can be converted into a `Number` with `into`,
+/// as in `Rule::Max(99.99.into())`.
+#[derive(Debug, Clone, Copy)]
+pub enum Number {
+    /// An unsigned integer.
+    Unsigned(u64),
+    /// A signed integer.
+    Signed(i64),
+    /// A floating point number.
+    Float(f64),
+}
+
+impl Number {
+    /// Returns the number in `value`, if it is numeric.
+    pub fn of(value: &Value) -> Option<Number> {
+        match *value {
+            Value::U64(value) => Some(Number::Unsigned(value)),
+            Value::I64(value) => Some(Number::Signed(value)),
+            Value::F64(value) => Some(Number::Float(value)),
+            _ => None,
+        }
+    }
+
+    /// Reads a number from the string `value`, such as form input, if it is not `NaN`.
+    pub(crate) fn parse(value: &str) -> Option<Number> {
+        if let Ok(value) = value.parse::<u64>() {
+            return Some(Number::Unsigned(value));
+        }
+        if let Ok(value) = value.parse::<i64>() {
+            return Some(Number::Signed(value));
+        }
+        match value.parse::<f64>() {
+            Ok(value) if !value.is_nan() => Some(Number::Float(value)),
+            _ => None,
+        }
+    }
+
+    /// Compares two numbers without loss of precision,
+    /// returning `None` if either is `NaN`.
+    pub fn compare(&self, other: &Number) -> Option<Ordering> {
+        match (*self, *other) {
+            (Number::Float(a), Number::Float(b)) => a.partial_cmp(&b),
+            (Number::Float(a), b) => compare_float(b.integer()?, a).map(Ordering::reverse),
+            (a, Number::Float(b)) => compare_float(a.integer()?, b),
+            (a, b) => Some(a.integer()?.cmp(&b.integer()?)),
+        }
+    }
+
+    fn integer(&self) -> Option<i128> {
+        match *self {
+            Number::Unsigned(value) => Some(i128::from(value)),
+            Number::Signed(value) => Some(i128::from(value)),
+            Number::Float(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Number) -> bool {
+        self.compare(other) == Some(Ordering::Equal)
+    }
+}
+
+macro_rules! number_from {
+    ($variant:ident, $inner:ty, $($from:ty),*) => {
+        $(
+            impl From<$from> for Number {
+                fn from(value: $from) -> Number {
+                    Number::$variant(value as $inner)
+                }
+            }
+        )*
+    }
+}
+
+number_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+number_from!(Signed, i64, i8, i16, i32, i64, isize);
+number_from!(Float, f64, f32, f64);
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Number::Unsigned(value) => write!(f, "{}", value),
+            Number::Signed(value) => write!(f, "{}", value),
+            Number::Float(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Compares the integer `a` with the float `b` exactly.
+fn compare_float(a: i128, b: f64) -> Option<Ordering> {
+    if b.is_nan() {
+        return None;
+    }
+    // Out of range floats saturate, which still orders them correctly against any `u64` or `i64`
+    let whole = b.trunc() as i128;
+    match a.cmp(&whole) {
+        Ordering::Equal => 0f64.partial_cmp(&b.fract()),
+        ordering => Some(ordering),
+    }
+}
+
+/// A decimal number held exactly, as `digits / 10^scale`, so that it can be divided
+/// without the rounding error of a float.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimal {
+    digits: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Parses a number written out in full, such as `-12.50` or `.5`,
+    /// keeping any trailing zeros in its scale.
+    pub fn parse(text: &str) -> Option<Decimal> {
+        let unsigned = text.trim_start_matches(['+', '-']);
+        if text.len() - unsigned.len() > 1 {
+            return None;
+        }
+        let (whole, fraction) = match unsigned.find('.') {
+            Some(point) => (&unsigned[..point], &unsigned[point + 1..]),
+            None => (unsigned, ""),
+        };
+        if whole.is_empty() && fraction.is_empty() ||
+           !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let digits = format!("{}{}", whole, fraction).parse::<i128>().ok()?;
+        Some(Decimal {
+            digits: if text.starts_with('-') { -digits } else { digits },
+            scale: u32::try_from(fraction.len()).ok()?,
+        })
+    }
+
+    /// Returns the number in `value`, if it is numeric or a string holding a number.
+    ///
+    /// Floats are taken at the shortest decimal which reads back as the same float,
+    /// so `0.1` is exactly one tenth.
+    pub fn of(value: &Value) -> Option<Decimal> {
+        match *value {
+            Value::String(ref value) => {
+                Decimal::parse(value).or_else(|| {
+                    let value = value.parse::<f64>().ok()?;
+                    Decimal::of_number(Number::Float(value))
+                })
+            }
+            ref value => Number::of(value).and_then(Decimal::of_number),
+        }
+    }
+
+    /// Returns `number` as a decimal, or `None` if it is not finite or has too many digits.
+    pub fn of_number(number: Number) -> Option<Decimal> {
+        match number {
+            Number::Float(value) if !value.is_finite() => None,
+            number => Decimal::parse(&number.to_string()),
+        }
+    }
+
+    /// The number of digits after the decimal point, including trailing zeros.
+    pub fn places(&self) -> usize {
+        self.scale as usize
+    }
+
+    /// Whether this is a whole multiple of `step`, returning `None` if `step` is zero
+    /// or the numbers cannot be brought to the same scale.
+    pub fn is_multiple_of(&self, step: &Decimal) -> Option<bool> {
+        let scale = self.scale.max(step.scale);
+        let value = self.digits.checked_mul(10i128.checked_pow(scale - self.scale)?)?;
+        let step = step.digits.checked_mul(10i128.checked_pow(scale - step.scale)?)?;
+        if step == 0 {
+            return None;
+        }
+        Some(value % step == 0)
+    }
+}
+
+/// How the length of a string is counted by the `Size` family of rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Length {
+    /// Counts Unicode scalar values, so `"Zoë"` has a length of 3. This is the default.
+    #[default]
+    Characters,
+    /// Counts extended grapheme clusters, which is what a user sees as a single character.
+    /// For example, an emoji with a skin tone modifier has a length of 1.
+    Graphemes,
+    /// Counts bytes of UTF-8, which is useful when guarding the width of a database column.
+    Bytes,
+}
+
+impl Length {
+    /// Returns the length of `value`, counted in this unit.
+    pub fn of(&self, value: &str) -> usize {
+        match *self {
+            Length::Characters => value.chars().count(),
+            Length::Graphemes => value.graphemes(true).count(),
+            Length::Bytes => value.len(),
+        }
+    }
+}
+
+/// The size of a value, as used by the `Size` family of rules.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Measure {
+    /// The length of a string.
+    Characters(usize),
+    /// The value of a number.
+    Number(Number),
+    /// The number of elements in an array or map.
+    Items(usize),
+    /// The size of a file in bytes, which is compared against bounds in kilobytes.
+    Bytes(u64),
+}
+
+impl Measure {
+    /// Returns the size of `value`, if it has one,
+    /// counting the length of a string with `length`.
+    pub fn of(value: &Value, length: Length) -> Option<Measure> {
+        match *value {
+            Value::String(ref value) => Some(Measure::Characters(length.of(value))),
+            Value::Array(ref value) => Some(Measure::Items(value.len())),
+            Value::Map(ref value) => Some(Measure::Items(value.len())),
+            Value::File(ref value) => Some(Measure::Bytes(value.size)),
+            ref value => Number::of(value).map(Measure::Number),
+        }
+    }
+
+    /// Whether this is the size of an empty string, array, or map,
+    /// which the size rules allow.
+    pub fn is_empty(&self) -> bool {
+        matches!(*self, Measure::Characters(0) | Measure::Items(0))
+    }
+
+    /// The size as a number, for comparing against the bound of a size rule.
+    pub fn number(&self) -> Number {
+        match *self {
+            Measure::Characters(size) | Measure::Items(size) => Number::from(size),
+            Measure::Number(number) => number,
+            Measure::Bytes(size) if size % 1024 == 0 => Number::from(size / 1024),
+            // Any whole number of bytes below 2^53 is exact as a float of kilobytes
+            Measure::Bytes(size) => Number::from(size as f64 / 1024.0),
+        }
+    }
+
+    /// Compares two sizes, returning `None` if they are not the same kind of size.
+    pub fn compare(&self, other: &Measure) -> Option<Ordering> {
+        match (*self, *other) {
+            (Measure::Characters(a), Measure::Characters(b)) |
+            (Measure::Items(a), Measure::Items(b)) => Some(a.cmp(&b)),
+            (Measure::Bytes(a), Measure::Bytes(b)) => Some(a.cmp(&b)),
+            (Measure::Number(a), Measure::Number(b)) => a.compare(&b),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Measure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Measure::Characters(size) | Measure::Items(size) => write!(f, "{}", size),
+            Measure::Number(number) => write!(f, "{}", number),
+            Measure::Bytes(_) => write!(f, "{}", self.number()),
+        }
+    }
+}
+
+/// The units a `FileSize` may be written in, as a name and the number of bytes in one unit.
+const UNITS: &[(&str, u64)] = &[("B", 1),
+                                ("KB", 1_000),
+                                ("MB", 1_000_000),
+                                ("GB", 1_000_000_000),
+                                ("TB", 1_000_000_000_000),
+                                ("KiB", 1 << 10),
+                                ("MiB", 1 << 20),
+                                ("GiB", 1 << 30),
+                                ("TiB", 1 << 40)];
+
+/// An exact size of a file in bytes, such as the bound given to `MaxFileSize`.
+///
+/// A size can be parsed from a number of bytes, such as `2048`, or from a number
+/// with a decimal or binary unit, such as `2.5MB` or `512KiB`. A parsed size is displayed
+/// in the unit it was written in, and any other size in the largest decimal unit that fits.
+#[derive(Debug, Clone, Copy)]
+pub struct FileSize {
+    bytes: u64,
+    unit: Option<usize>,
+}
+
+impl FileSize {
+    /// Creates a size of the given number of bytes.
+    pub fn bytes(bytes: u64) -> FileSize {
+        FileSize {
+            bytes,
+            unit: None,
+        }
+    }
+
+    /// The size in bytes.
+    pub fn as_bytes(&self) -> u64 {
+        self.bytes
+    }
+}
+
+impl PartialEq for FileSize {
+    fn eq(&self, other: &FileSize) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for FileSize {}
+
+impl From<u64> for FileSize {
+    fn from(bytes: u64) -> FileSize {
+        FileSize::bytes(bytes)
+    }
+}
+
+impl FromStr for FileSize {
+    type Err = ParseError;
+
+    fn from_str(size: &str) -> Result<FileSize, ParseError> {
+        let error = || ParseError::InvalidFileSize(size.to_owned());
+        let trimmed = size.trim();
+        let split = trimmed.find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(trimmed.len());
+        let (number, unit) = (&trimmed[..split], trimmed[split..].trim());
+        let index = if unit.is_empty() {
+            0
+        } else {
+            UNITS.iter()
+                .position(|&(name, _)| name.eq_ignore_ascii_case(unit))
+                .ok_or_else(error)?
+        };
+        let (whole, fraction) = match number.find('.') {
+            Some(point) => (&number[..point], &number[point + 1..]),
+            None => (number, ""),
+        };
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(error());
+        }
+        // Compute the size exactly, as `digits / 10^scale` units
+        let digits = format!("{}{}", whole, fraction).parse::<u128>().map_err(|_| error())?;
+        let scale = 10u128.checked_pow(fraction.len() as u32).ok_or_else(error)?;
+        let bytes = digits.checked_mul(u128::from(UNITS[index].1)).ok_or_else(error)?;
+        if bytes % scale != 0 {
+            return Err(error());
+        }
+        let bytes = u64::try_from(bytes / scale).map_err(|_| error())?;
+        Ok(FileSize {
+            bytes,
+            unit: if unit.is_empty() { None } else { Some(index) },
+        })
+    }
+}
+
+impl fmt::Display for FileSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let index = self.unit.unwrap_or_else(|| {
+            UNITS[..5].iter().rposition(|&(_, factor)| self.bytes >= factor).unwrap_or(0)
+        });
+        match UNITS[index] {
+            (_, 1) if self.bytes == 1 => f.write_str("1 byte"),
+            (_, 1) => write!(f, "{} bytes", self.bytes),
+            (name, factor) if self.bytes % factor == 0 => {
+                write!(f, "{} {}", self.bytes / factor, name)
+            }
+            (name, factor) => {
+                let value = format!("{:.2}", self.bytes as f64 / factor as f64);
+                write!(f, "{} {}", value.trim_end_matches('0').trim_end_matches('.'), name)
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use error::{Param, ValidationError};
+use options::Options;
+
+/// Returns the display name of a dotted field path,
+/// which is the last segment in lowercase with underscores replaced by spaces.
+pub fn attribute(field: &str) -> String {
+    field.rsplit('.')
+        .next()
+        .unwrap()
+        .to_lowercase()
+        .replace("_", " ")
+}
+
+/// Substitutes the placeholders in `template` using the parameters of `error`.
+///
+/// `:attribute` is replaced by the display name of the field,
+/// and `:name` by the parameter called `name`, where other fields are
+/// also replaced by their display names.
+/// Placeholders that do not match a parameter are left as they are.
+pub fn render(template: &str, error: &ValidationError, options: &Options) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(':') {
+        message.push_str(&rest[..start]);
+        let name_len = rest[start + 1..]
+            .find(|c: char| !c.is_ascii_lowercase() && c != '_')
+            .unwrap_or(rest.len() - start - 1);
+        let name = &rest[start + 1..start + 1 + name_len];
+        match (name, error.param(name)) {
+            ("attribute", _) => message.push_str(&options.attribute_name(error.field())),
+            (_, Some(&Param::Fields(ref fields))) => {
+                let names = fields.iter()
+                    .map(|field| options.attribute_name(field))
+                    .collect::<Vec<String>>();
+                message.push_str(&names.join(", "));
+            }
+            (_, Some(param)) => message.push_str(&param.to_string()),
+            (_, None) => message.push_str(&rest[start..start + 1 + name_len]),
+        }
+        rest = &rest[start + 1 + name_len..];
+    }
+    message.push_str(rest);
+    message
+}
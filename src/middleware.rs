@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use iron::headers::ContentType;
+use iron::status;
+use iron::typemap::Key;
+use iron::{BeforeMiddleware, IronError, IronResult, Plugin, Request, Response};
+use params::{Map, Params};
+use serde_json;
+
+use error::ValidationError;
+use options::Options;
+use {Rule, validate_with};
+
+type Rules = BTreeMap<&'static str, Vec<Rule<'static>>>;
+type Errors = BTreeMap<String, Vec<ValidationError>>;
+
+/// The key under which `Validate` stores the validated values in the request's extensions.
+///
+/// ```ignore
+/// let values = req.extensions.get::<Validated>().unwrap();
+/// ```
+pub struct Validated;
+
+impl Key for Validated {
+    type Value = Map;
+}
+
+/// Middleware which validates the parameters of each request before it reaches the handler.
+///
+/// Parameters are read with the `params` plugin and validated with `validate_with`.
+/// If they are valid, the post-processed values are stored in the request's extensions
+/// under `Validated`. Otherwise, the request is rejected with `422 Unprocessable Entity`
+/// and a JSON body of the error messages for each field, such as
+/// `{"errors": {"email": ["The email field is required."]}}`.
+/// Requests whose parameters cannot be read are rejected with `400 Bad Request`.
+///
+/// ```ignore
+/// let mut chain = Chain::new(handler);
+/// chain.link_before(Validate::new(|| {
+///     let mut rules = BTreeMap::new();
+///     rules.insert("email", vec![Rule::Required, Rule::Email(EmailOptions::new())]);
+///     rules
+/// }));
+/// ```
+pub struct Validate {
+    rules: Box<dyn Fn() -> Rules + Send + Sync>,
+    options: Options,
+    response: Box<dyn Fn(&Errors) -> Response + Send + Sync>,
+}
+
+impl Validate {
+    /// Creates middleware which validates requests against the rules returned by `rules`.
+    ///
+    /// The rules are created for each request, because a `Rule` is not always safe
+    /// to share between the threads handling requests.
+    pub fn new<F>(rules: F) -> Validate
+        where F: Fn() -> Rules + Send + Sync + 'static
+    {
+        Validate {
+            rules: Box::new(rules),
+            options: Options::new(),
+            response: Box::new(default_response),
+        }
+    }
+
+    /// Sets the options passed to `validate_with`.
+    pub fn options(mut self, options: Options) -> Validate {
+        self.options = options;
+        self
+    }
+
+    /// Sets the function which builds the response for a request that fails validation.
+    pub fn response<F>(mut self, response: F) -> Validate
+        where F: Fn(&Errors) -> Response + Send + Sync + 'static
+    {
+        self.response = Box::new(response);
+        self
+    }
+}
+
+impl BeforeMiddleware for Validate {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        let values = match req.get::<Params>() {
+            Ok(values) => values,
+            Err(err) => return Err(IronError::new(err, status::BadRequest)),
+        };
+        match validate_with(&(self.rules)(), values, &self.options) {
+            Ok(values) => {
+                req.extensions.insert::<Validated>(values);
+                Ok(())
+            }
+            Err(errors) => {
+                Err(IronError {
+                    response: (self.response)(&errors),
+                    error: Box::new(ValidationFailed(errors)),
+                })
+            }
+        }
+    }
+}
+
+/// Responds with `422 Unprocessable Entity` and the error messages for each field as JSON.
+fn default_response(errors: &Errors) -> Response {
+    let mut fields = serde_json::Map::new();
+    for (field, messages) in errors {
+        let messages = messages.iter()
+            .map(|error| serde_json::Value::String(error.message().to_owned()))
+            .collect();
+        fields.insert(field.clone(), serde_json::Value::Array(messages));
+    }
+    let mut body = serde_json::Map::new();
+    body.insert("errors".to_owned(), serde_json::Value::Object(fields));
+    let mut response = Response::with((status::UnprocessableEntity,
+                                       serde_json::Value::Object(body).to_string()));
+    response.headers.set(ContentType::json());
+    response
+}
+
+/// The error passed on by `Validate` when a request fails validation,
+/// which can be inspected by later middleware.
+#[derive(Debug)]
+pub struct ValidationFailed(pub BTreeMap<String, Vec<ValidationError>>);
+
+impl fmt::Display for ValidationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Validation failed for {} field(s)", self.0.len())
+    }
+}
+
+impl Error for ValidationFailed {}
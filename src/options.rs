@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use error::ValidationError;
+use measure::Length;
+use messages;
+use resolve::{self, Resolver};
+use translate::{self, ENGLISH, Translator};
+
+/// Options which customize how `validate_with` validates and reports errors.
+#[derive(Clone, Default)]
+pub struct Options {
+    messages: BTreeMap<String, String>,
+    attributes: BTreeMap<String, String>,
+    locale: Option<String>,
+    translator: Option<Arc<dyn Translator>>,
+    resolver: Option<Arc<dyn Resolver>>,
+    bail: bool,
+    length: Length,
+}
+
+impl Options {
+    /// Creates options with the default behavior of `validate`.
+    pub fn new() -> Options {
+        Options::default()
+    }
+
+    /// Sets a custom message for a rule, replacing its built-in message.
+    ///
+    /// The key is either a rule code, such as `required`, which applies to every field,
+    /// or a field name followed by a rule code, such as `password.min`.
+    /// The field name may be a concrete path or use wildcards, as in `items.*.qty.min`,
+    /// and `*.required` applies to every field like `required` does.
+    ///
+    /// The message for a field and rule is preferred over the message for a rule,
+    /// which is preferred over the built-in message.
+    ///
+    /// Messages may contain placeholders which are replaced by the name of the field
+    /// and the rule's parameters: `:attribute` is the name of the field, and placeholders
+    /// such as `:min`, `:max`, `:size`, `:other`, and `:values` are the parameters of the rule.
+    pub fn message<K, V>(mut self, key: K, message: V) -> Options
+        where K: Into<String>,
+              V: Into<String>
+    {
+        self.messages.insert(key.into(), message.into());
+        self
+    }
+
+    /// Sets the name used for a field in error messages, such as `date of birth` for `dob`.
+    ///
+    /// The field name may be a concrete path or use wildcards, as in `items.*.qty`.
+    /// Names are used both for the field under validation and for other fields
+    /// referenced by a rule, such as the field given to `Same`.
+    ///
+    /// By default, a field is named by the last segment of its path in lowercase,
+    /// with underscores replaced by spaces.
+    pub fn attribute<K, V>(mut self, field: K, name: V) -> Options
+        where K: Into<String>,
+              V: Into<String>
+    {
+        self.attributes.insert(field.into(), name.into());
+        self
+    }
+
+    /// Whether to stop running the rules for each field after the first rule that fails,
+    /// as if every field had the `Bail` rule. Defaults to `false`.
+    pub fn bail(mut self, bail: bool) -> Options {
+        self.bail = bail;
+        self
+    }
+
+    /// Sets how the length of a string is counted by the `Size` family of rules,
+    /// for every field without a `Length` rule. Defaults to `Length::Characters`.
+    pub fn length(mut self, length: Length) -> Options {
+        self.length = length;
+        self
+    }
+
+    /// Sets the locale used for error messages, such as `pt-BR`.
+    ///
+    /// Messages are looked up in the translator for the locale, then for each less specific
+    /// locale, and finally in English. For example, `pt-BR` falls back to `pt`, then `en`.
+    /// Custom messages set with `message` are used regardless of the locale.
+    pub fn locale<L: Into<String>>(mut self, locale: L) -> Options {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Sets the source of translated messages, such as a `Catalog`.
+    ///
+    /// Any message which the translator does not provide falls back to the built-in English.
+    pub fn translator<T: Translator + 'static>(mut self, translator: T) -> Options {
+        self.translator = Some(Arc::new(translator));
+        self
+    }
+
+    /// Sets where DNS records are looked up for the `ActiveUrl` rule and `EmailOptions::dns`,
+    /// such as a `StaticResolver` in tests.
+    ///
+    /// By default, records are looked up on the network with a `SystemResolver`,
+    /// and kept for five minutes by a `CachingResolver` shared by every `Options`.
+    pub fn resolver<R: Resolver + 'static>(mut self, resolver: R) -> Options {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Whether every field should stop at its first failure.
+    pub(crate) fn bails(&self) -> bool {
+        self.bail
+    }
+
+    /// How the length of a string is counted, unless a field sets its own.
+    pub(crate) fn string_length(&self) -> Length {
+        self.length
+    }
+
+    /// Where DNS records are looked up.
+    pub(crate) fn dns_resolver(&self) -> &dyn Resolver {
+        match self.resolver {
+            Some(ref resolver) => &**resolver,
+            None => resolve::default_resolver(),
+        }
+    }
+
+    /// Returns the template for the message key `key` in the selected locale.
+    pub(crate) fn template(&self, key: &str, count: Option<f64>) -> String {
+        if let Some(ref translator) = self.translator {
+            let locale = self.locale.as_deref().unwrap_or("en");
+            for locale in translate::fallback_chain(locale) {
+                if let Some(template) = translator.translate(&locale, key, count) {
+                    return template;
+                }
+            }
+        }
+        ENGLISH.translate("en", key, count)
+            .or_else(|| ENGLISH.translate("en", "invalid", None))
+            .unwrap_or_default()
+    }
+
+    /// Returns the display name of the field at the dotted path `field`.
+    ///
+    /// A name set for the exact path is preferred over one set using wildcards.
+    pub(crate) fn attribute_name(&self, field: &str) -> String {
+        if let Some(name) = self.attributes.get(field) {
+            return name.clone();
+        }
+        self.attributes
+            .iter()
+            .find(|&(pattern, _)| matches_pattern(pattern, field))
+            .map(|(_, name)| name.clone())
+            .unwrap_or_else(|| messages::attribute(field))
+    }
+
+    /// Returns the custom message for `error`, if one was set.
+    ///
+    /// `pattern` is the field name from the rules, which may contain wildcards.
+    pub(crate) fn custom_message(&self, pattern: &str, error: &ValidationError) -> Option<&str> {
+        let code = error.code();
+        [format!("{}.{}", error.field(), code),
+         format!("{}.{}", pattern, code),
+         format!("*.{}", code),
+         code.to_owned()]
+            .iter()
+            .filter_map(|key| self.messages.get(key))
+            .map(|message| message.as_str())
+            .next()
+    }
+}
+
+impl fmt::Debug for Options {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Options")
+            .field("messages", &self.messages)
+            .field("attributes", &self.attributes)
+            .field("locale", &self.locale)
+            .field("translator", &self.translator.as_ref().map(|_| "Translator"))
+            .field("resolver", &self.resolver.as_ref().map(|_| "Resolver"))
+            .field("bail", &self.bail)
+            .field("length", &self.length)
+            .finish()
+    }
+}
+
+/// Whether the dotted path `field` matches `pattern`,
+/// where each `*` segment in `pattern` matches any one segment.
+fn matches_pattern(pattern: &str, field: &str) -> bool {
+    let mut segments = field.split('.');
+    pattern.split('.').all(|expected| match segments.next() {
+        Some(segment) => expected == "*" || expected == segment,
+        None => false,
+    }) && segments.next().is_none()
+}
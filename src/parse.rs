@@ -0,0 +1,608 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use params::Value;
+use regex::Regex;
+use {Dimensions, EmailMode, EmailOptions, FileSize, Length, MacAddressOptions, MacNotation, Number,
+     Rule, TimezoneOptions, UrlOptions, UuidOptions};
+
+/// The schemes which the `url` rule may be given by name, as in `url:http,https`.
+const KNOWN_SCHEMES: &[&str] = &["data", "file", "ftp", "ftps", "git", "http", "https", "irc",
+                                 "ircs", "ldap", "ldaps", "mailto", "news", "nntp", "rtsp", "sftp",
+                                 "sip", "sips", "smb", "ssh", "svn", "tel", "telnet", "ws", "wss",
+                                 "xmpp"];
+
+/// An error encountered while parsing a rule string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The rule name is not recognized.
+    UnknownRule(String),
+    /// The rule is recognized, but cannot be expressed as a string,
+    /// such as `exists` and `unique`, which need a database connection.
+    UnsupportedRule(String),
+    /// The rule was given the wrong number of arguments.
+    WrongArity {
+        /// The name of the rule.
+        rule: String,
+        /// A description of the number of arguments the rule expects.
+        expected: &'static str,
+        /// The number of arguments that were given.
+        found: usize,
+    },
+    /// An argument that must be an integer could not be parsed as one.
+    InvalidInteger {
+        /// The name of the rule.
+        rule: String,
+        /// The argument that could not be parsed.
+        argument: String,
+    },
+    /// An argument that must be a number could not be parsed as one.
+    InvalidNumber {
+        /// The name of the rule.
+        rule: String,
+        /// The argument that could not be parsed.
+        argument: String,
+    },
+    /// An argument is not one of the values that the rule accepts.
+    InvalidArgument {
+        /// The name of the rule.
+        rule: String,
+        /// The argument that was not recognized.
+        argument: String,
+    },
+    /// A file size could not be parsed, as it is not a whole number of bytes
+    /// or a number with a unit such as `2.5MB` or `512KiB`.
+    InvalidFileSize(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::UnknownRule(ref rule) => write!(f, "Unknown rule \"{}\"", rule),
+            ParseError::UnsupportedRule(ref rule) => {
+                write!(f, "The \"{}\" rule cannot be parsed from a string", rule)
+            }
+            ParseError::WrongArity { ref rule, expected, found } => {
+                write!(f,
+                       "The \"{}\" rule expects {}, but {} were given",
+                       rule,
+                       expected,
+                       found)
+            }
+            ParseError::InvalidInteger { ref rule, ref argument } => {
+                write!(f,
+                       "The \"{}\" rule expects an integer, but \"{}\" was given",
+                       rule,
+                       argument)
+            }
+            ParseError::InvalidNumber { ref rule, ref argument } => {
+                write!(f,
+                       "The \"{}\" rule expects a number, but \"{}\" was given",
+                       rule,
+                       argument)
+            }
+            ParseError::InvalidArgument { ref rule, ref argument } => {
+                write!(f, "The \"{}\" rule does not accept \"{}\"", rule, argument)
+            }
+            ParseError::InvalidFileSize(ref size) => {
+                write!(f,
+                       "\"{}\" is not a file size, such as \"2048\", \"2.5MB\", or \"512KiB\"",
+                       size)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl<'a> Rule<'a> {
+    /// Parses a single Laravel-style rule, such as `max:255` or `required_if:type,business`.
+    ///
+    /// Arguments follow the rule name after a `:` and are separated by commas.
+    /// The values given to `in`, `not_in`, `required_if`, and `required_unless`
+    /// are compared as strings. As in Laravel, `in_array` may be given
+    /// `anotherfield.*`; the trailing `.*` is ignored.
+    ///
+    /// The `regex` and `date_format` rules take everything after the `:` as their argument,
+    /// commas included. As in Laravel, a `regex` pattern may be wrapped in `/`, `#`, or `~`
+    /// delimiters, followed by the flags `i`, `m`, `s`, `x`, `U`, and `u`,
+    /// as in `regex:/^[a-z]+$/i`.
+    /// A pattern which does not start with one of these delimiters is used as it is,
+    /// and a pattern which does not compile fails with `ParseError::InvalidArgument`.
+    /// The `required_if` and `required_unless` rules take a field and one or more values,
+    /// as in `required_if:type,business,charity`.
+    /// The `timezone` rule may be given `canonical` and `offset`, which enable
+    /// the corresponding `TimezoneOptions`, as in `timezone:canonical,offset`.
+    /// The `decimal` rule takes a number of places, or a minimum and maximum, as in `decimal:1,2`,
+    /// where the minimum may not be greater than the maximum.
+    /// The `dimensions` rule takes constraints such as `min_width=100` and `ratio=3/2`.
+    /// The `max_file_size` and `min_file_size` rules take a size such as `2.5MB`.
+    /// The `email` rule may be given a mode, `rfc` or `html5`, as well as `no_quoted`,
+    /// `no_ip_literal`, `lowercase_domain`, and `dns`.
+    /// The `mac_address` rule may be given `unicast`, `universal`, and a notation
+    /// to transform addresses to, such as `colon` or `dot`.
+    /// The `starts_with`, `ends_with`, `doesnt_start_with`, and `doesnt_end_with` rules
+    /// take a list of prefixes or suffixes, which are matched exactly.
+    /// The `url` rule may be given schemes to accept, as in `url:http,https`, as well as `host`,
+    /// `no_credentials`, `no_ip`, `no_private`, `normalize`, and a `max_length` such as
+    /// `max_length=2048`. Common schemes may be given by name, and any other scheme
+    /// as in `scheme=svn+ssh`, so that a misspelled option is an error rather than a scheme.
+    /// The `uuid` rule may be given versions, such as `uuid:4,7`, and `canonical`.
+    /// The `exists` and `unique` rules cannot be parsed, since they need a database connection.
+    pub fn parse(rule: &'a str) -> Result<Rule<'a>, ParseError> {
+        let (name, args) = match rule.find(':') {
+            Some(index) => (&rule[..index], Some(&rule[index + 1..])),
+            None => (rule, None),
+        };
+        match (name, args) {
+            ("regex", Some(pattern)) => return parse_pattern(name, pattern).map(Rule::Regex),
+            ("date_format", Some(format)) => return Ok(Rule::DateFormat(format)),
+            ("regex", None) | ("date_format", None) => {
+                return Err(wrong_arity(name, "1 argument", 0));
+            }
+            _ => (),
+        }
+        let args = args.map(|args| args.split(',').collect::<Vec<&str>>())
+            .unwrap_or_default();
+
+        Ok(match name {
+            "accepted" => expect_none(name, &args, Rule::Accepted)?,
+            "active_url" => expect_none(name, &args, Rule::ActiveUrl)?,
+            "after" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::After(args[0])
+            }
+            "after_or_equal" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::AfterOrEqual(args[0])
+            }
+            "alpha" => expect_none(name, &args, Rule::Alpha)?,
+            "alpha_dash" => expect_none(name, &args, Rule::AlphaDash)?,
+            "alpha_num" => expect_none(name, &args, Rule::AlphaNumeric)?,
+            "array" => expect_none(name, &args, Rule::Array)?,
+            "ascii" => expect_none(name, &args, Rule::Ascii)?,
+            "bail" => expect_none(name, &args, Rule::Bail)?,
+            "before" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Before(args[0])
+            }
+            "before_or_equal" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::BeforeOrEqual(args[0])
+            }
+            "between" => {
+                expect_count(name, &args, 2, "2 arguments")?;
+                Rule::Between(parse_number(name, args[0])?, parse_number(name, args[1])?)
+            }
+            "boolean" => expect_none(name, &args, Rule::Boolean)?,
+            "confirmed" => expect_none(name, &args, Rule::Confirmed)?,
+            "date" => expect_none(name, &args, Rule::Date)?,
+            "date_equals" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::DateEquals(args[0])
+            }
+            "decimal" => {
+                match args.len() {
+                    1 => {
+                        let places = parse_integer(name, args[0])?;
+                        Rule::Decimal(places, places)
+                    }
+                    2 => {
+                        let min = parse_integer(name, args[0])?;
+                        let max = parse_integer(name, args[1])?;
+                        if min > max {
+                            return Err(invalid_argument(name, args[1]));
+                        }
+                        Rule::Decimal(min, max)
+                    }
+                    found => return Err(wrong_arity(name, "1 or 2 arguments", found)),
+                }
+            }
+            "different" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Different(args[0])
+            }
+            "digits" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Digits(parse_integer(name, args[0])?)
+            }
+            "digits_between" => {
+                expect_count(name, &args, 2, "2 arguments")?;
+                Rule::DigitsBetween(parse_integer(name, args[0])?, parse_integer(name, args[1])?)
+            }
+            "dimensions" => {
+                expect_some(name, &args)?;
+                let mut dimensions = Dimensions::new();
+                for arg in &args {
+                    dimensions = parse_dimension(dimensions, arg)
+                        .ok_or_else(|| invalid_argument(name, arg))?;
+                }
+                Rule::Dimensions(dimensions)
+            }
+            "distinct" => expect_none(name, &args, Rule::Distinct)?,
+            "doesnt_end_with" => {
+                expect_some(name, &args)?;
+                Rule::DoesntEndWith(args)
+            }
+            "doesnt_start_with" => {
+                expect_some(name, &args)?;
+                Rule::DoesntStartWith(args)
+            }
+            "email" => {
+                let mut options = EmailOptions::new();
+                for arg in &args {
+                    options = match *arg {
+                        "rfc" => options.mode(EmailMode::Rfc),
+                        "html5" => options.mode(EmailMode::Html5),
+                        "no_quoted" => options.quoted(false),
+                        "no_ip_literal" => options.ip_literal(false),
+                        "lowercase_domain" => options.lowercase_domain(true),
+                        "dns" => options.dns(true),
+                        _ => return Err(invalid_argument(name, arg)),
+                    };
+                }
+                Rule::Email(options)
+            }
+            "ends_with" => {
+                expect_some(name, &args)?;
+                Rule::EndsWith(args)
+            }
+            "file" => expect_none(name, &args, Rule::File)?,
+            "filled" => expect_none(name, &args, Rule::Filled)?,
+            "gt" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Gt(args[0])
+            }
+            "gte" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Gte(args[0])
+            }
+            "image" => expect_none(name, &args, Rule::Image)?,
+            "in" => {
+                expect_some(name, &args)?;
+                Rule::In(args.iter().map(|arg| Value::String(arg.to_string())).collect())
+            }
+            "in_array" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::InArray(args[0].trim_end_matches(".*"))
+            }
+            "integer" => expect_none(name, &args, Rule::Integer)?,
+            "ip" => expect_none(name, &args, Rule::IpAddress)?,
+            "json" => expect_none(name, &args, Rule::Json)?,
+            "length" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Length(match args[0] {
+                    "characters" => Length::Characters,
+                    "graphemes" => Length::Graphemes,
+                    "bytes" => Length::Bytes,
+                    arg => return Err(invalid_argument(name, arg)),
+                })
+            }
+            "lowercase" => expect_none(name, &args, Rule::Lowercase)?,
+            "lt" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Lt(args[0])
+            }
+            "lte" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Lte(args[0])
+            }
+            "mac_address" => {
+                let mut options = MacAddressOptions::new();
+                for arg in &args {
+                    options = match *arg {
+                        "unicast" => options.unicast(true),
+                        "universal" => options.universal(true),
+                        "colon" => options.notation(MacNotation::Colon),
+                        "hyphen" => options.notation(MacNotation::Hyphen),
+                        "dot" => options.notation(MacNotation::Dot),
+                        "bare" => options.notation(MacNotation::Bare),
+                        _ => return Err(invalid_argument(name, arg)),
+                    };
+                }
+                Rule::MacAddress(options)
+            }
+            "max" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Max(parse_number(name, args[0])?)
+            }
+            "max_file_size" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::MaxFileSize(args[0].parse::<FileSize>()?)
+            }
+            "mimes" => {
+                expect_some(name, &args)?;
+                Rule::Mimes(args)
+            }
+            "mimetypes" => {
+                expect_some(name, &args)?;
+                Rule::MimeTypes(args)
+            }
+            "min" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Min(parse_number(name, args[0])?)
+            }
+            "min_file_size" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::MinFileSize(args[0].parse::<FileSize>()?)
+            }
+            "multiple_of" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::MultipleOf(parse_number(name, args[0])?)
+            }
+            "not_in" => {
+                expect_some(name, &args)?;
+                Rule::NotIn(args.iter().map(|arg| Value::String(arg.to_string())).collect())
+            }
+            "not_in_array" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::NotInArray(args[0].trim_end_matches(".*"))
+            }
+            "nullable" => expect_none(name, &args, Rule::Nullable)?,
+            "numeric" => expect_none(name, &args, Rule::Numeric)?,
+            "present" => expect_none(name, &args, Rule::Present)?,
+            "required" => expect_none(name, &args, Rule::Required)?,
+            "required_if" => {
+                expect_field_and_values(name, &args)?;
+                Rule::RequiredIf(args[0],
+                                 args[1..]
+                                     .iter()
+                                     .map(|arg| Value::String(arg.to_string()))
+                                     .collect())
+            }
+            "required_unless" => {
+                expect_field_and_values(name, &args)?;
+                Rule::RequiredUnless(args[0],
+                                     args[1..]
+                                         .iter()
+                                         .map(|arg| Value::String(arg.to_string()))
+                                         .collect())
+            }
+            "required_with" => {
+                expect_some(name, &args)?;
+                Rule::RequiredWith(args)
+            }
+            "required_with_all" => {
+                expect_some(name, &args)?;
+                Rule::RequiredWithAll(args)
+            }
+            "required_without" => {
+                expect_some(name, &args)?;
+                Rule::RequiredWithout(args)
+            }
+            "required_without_all" => {
+                expect_some(name, &args)?;
+                Rule::RequiredWithoutAll(args)
+            }
+            "same" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Same(args[0])
+            }
+            "size" => {
+                expect_count(name, &args, 1, "1 argument")?;
+                Rule::Size(parse_number(name, args[0])?)
+            }
+            "sometimes" => expect_none(name, &args, Rule::Sometimes)?,
+            "starts_with" => {
+                expect_some(name, &args)?;
+                Rule::StartsWith(args)
+            }
+            "string" => expect_none(name, &args, Rule::String)?,
+            "timezone" => {
+                let mut options = TimezoneOptions::new();
+                for arg in &args {
+                    options = match *arg {
+                        "canonical" => options.canonicalize(true),
+                        "offset" => options.offsets(true),
+                        _ => return Err(invalid_argument(name, arg)),
+                    };
+                }
+                Rule::Timezone(options)
+            }
+            "ulid" => expect_none(name, &args, Rule::Ulid)?,
+            "uppercase" => expect_none(name, &args, Rule::Uppercase)?,
+            "url" => {
+                let mut options = UrlOptions::new();
+                for arg in &args {
+                    options = match *arg {
+                        "host" => options.host(true),
+                        "no_credentials" => options.credentials(false),
+                        "no_ip" => options.ip_host(false),
+                        "no_private" => options.private_host(false),
+                        "normalize" => options.normalize(true),
+                        arg if arg.starts_with("max_length=") => {
+                            options.max_length(parse_integer(name, &arg["max_length=".len()..])?)
+                        }
+                        arg if arg.starts_with("scheme=") && is_scheme(&arg["scheme=".len()..]) => {
+                            options.scheme(&arg["scheme=".len()..])
+                        }
+                        scheme if is_known_scheme(scheme) => options.scheme(scheme),
+                        _ => return Err(invalid_argument(name, arg)),
+                    };
+                }
+                Rule::Url(options)
+            }
+            "uuid" => {
+                let mut options = UuidOptions::new();
+                for arg in &args {
+                    options = match *arg {
+                        "canonical" => options.canonical(true),
+                        version => {
+                            match version.parse::<u8>() {
+                                Ok(version @ 1..=8) => options.version(version),
+                                _ => return Err(invalid_argument(name, arg)),
+                            }
+                        }
+                    };
+                }
+                Rule::Uuid(options)
+            }
+            "exists" | "unique" => return Err(ParseError::UnsupportedRule(name.to_owned())),
+            _ => return Err(ParseError::UnknownRule(name.to_owned())),
+        })
+    }
+
+    /// Parses a list of Laravel-style rules separated by `|`,
+    /// such as `required|email|max:255`.
+    ///
+    /// See `Rule::parse` for the format of each rule.
+    /// Because `|` separates rules, a `regex` pattern containing `|`
+    /// must be given as a `Rule::Regex` instead.
+    pub fn parse_list(rules: &'a str) -> Result<Vec<Rule<'a>>, ParseError> {
+        rules.split('|')
+            .filter(|rule| !rule.is_empty())
+            .map(Rule::parse)
+            .collect()
+    }
+}
+
+fn wrong_arity(name: &str, expected: &'static str, found: usize) -> ParseError {
+    ParseError::WrongArity {
+        rule: name.to_owned(),
+        expected,
+        found,
+    }
+}
+
+/// Converts a `regex` argument wrapped in PCRE delimiters, such as `/^[a-z]+$/i`,
+/// to a pattern with inline flags, such as `(?i)^[a-z]+$`, and checks that it compiles.
+fn parse_pattern<'a>(name: &str, pattern: &'a str) -> Result<Cow<'a, str>, ParseError> {
+    let converted = strip_delimiters(pattern).ok_or_else(|| invalid_argument(name, pattern))?;
+    match Regex::new(&converted) {
+        Ok(_) => Ok(converted),
+        Err(_) => Err(invalid_argument(name, pattern)),
+    }
+}
+
+/// Removes the PCRE delimiters around `pattern`, if it has any, and turns its flags
+/// into inline flags. Returns `None` if the delimiters or flags are malformed.
+fn strip_delimiters(pattern: &str) -> Option<Cow<'_, str>> {
+    let delimiter = match pattern.chars().next() {
+        Some(delimiter @ '/') | Some(delimiter @ '#') | Some(delimiter @ '~') => delimiter,
+        _ => return Some(Cow::Borrowed(pattern)),
+    };
+    let end = match pattern.rfind(delimiter) {
+        Some(end) if end > 0 => end,
+        _ => return None,
+    };
+    let (inner, modifiers) = (&pattern[1..end], &pattern[end + 1..]);
+    let mut flags = String::new();
+    for modifier in modifiers.chars() {
+        match modifier {
+            'i' | 'm' | 's' | 'x' | 'U' => flags.push(modifier),
+            // Patterns always match Unicode text
+            'u' => (),
+            _ => return None,
+        }
+    }
+    if flags.is_empty() {
+        Some(Cow::Borrowed(inner))
+    } else {
+        Some(Cow::Owned(format!("(?{}){}", flags, inner)))
+    }
+}
+
+/// Whether `scheme` is one of the `KNOWN_SCHEMES`, compared case-insensitively.
+fn is_known_scheme(scheme: &str) -> bool {
+    KNOWN_SCHEMES.iter().any(|known| known.eq_ignore_ascii_case(scheme))
+}
+
+/// Whether `scheme` is a URL scheme, such as `https` or `svn+ssh`.
+fn is_scheme(scheme: &str) -> bool {
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic()) &&
+    scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+}
+
+/// Applies a constraint such as `min_width=100` or `ratio=3/2` to `dimensions`.
+fn parse_dimension(dimensions: Dimensions, arg: &str) -> Option<Dimensions> {
+    let mut parts = arg.splitn(2, '=');
+    let (name, value) = (parts.next()?, parts.next()?);
+    if name == "ratio" {
+        let mut terms = value.splitn(2, '/');
+        let width = terms.next()?.parse().ok()?;
+        let height = terms.next().map_or(Some(1), |height| height.parse().ok())?;
+        return Some(dimensions.ratio(width, height));
+    }
+    let value = value.parse().ok()?;
+    Some(match name {
+        "min_width" => dimensions.min_width(value),
+        "max_width" => dimensions.max_width(value),
+        "min_height" => dimensions.min_height(value),
+        "max_height" => dimensions.max_height(value),
+        "width" => dimensions.width(value),
+        "height" => dimensions.height(value),
+        _ => return None,
+    })
+}
+
+fn invalid_argument(name: &str, argument: &str) -> ParseError {
+    ParseError::InvalidArgument {
+        rule: name.to_owned(),
+        argument: argument.to_owned(),
+    }
+}
+
+fn expect_none<'a>(name: &str, args: &[&str], rule: Rule<'a>) -> Result<Rule<'a>, ParseError> {
+    if args.is_empty() {
+        Ok(rule)
+    } else {
+        Err(wrong_arity(name, "no arguments", args.len()))
+    }
+}
+
+fn expect_some(name: &str, args: &[&str]) -> Result<(), ParseError> {
+    if args.is_empty() {
+        Err(wrong_arity(name, "at least 1 argument", 0))
+    } else {
+        Ok(())
+    }
+}
+
+fn expect_field_and_values(name: &str, args: &[&str]) -> Result<(), ParseError> {
+    if args.len() >= 2 {
+        Ok(())
+    } else {
+        Err(wrong_arity(name, "at least 2 arguments", args.len()))
+    }
+}
+
+fn expect_count(name: &str,
+                args: &[&str],
+                count: usize,
+                expected: &'static str)
+                -> Result<(), ParseError> {
+    if args.len() == count {
+        Ok(())
+    } else {
+        Err(wrong_arity(name, expected, args.len()))
+    }
+}
+
+fn parse_integer<T: FromStr>(name: &str, argument: &str) -> Result<T, ParseError> {
+    argument.parse::<T>().map_err(|_| {
+        ParseError::InvalidInteger {
+            rule: name.to_owned(),
+            argument: argument.to_owned(),
+        }
+    })
+}
+
+fn parse_number(name: &str, argument: &str) -> Result<Number, ParseError> {
+    if let Ok(value) = argument.parse::<u64>() {
+        return Ok(Number::Unsigned(value));
+    }
+    if let Ok(value) = argument.parse::<i64>() {
+        return Ok(Number::Signed(value));
+    }
+    match argument.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(Number::Float(value)),
+        _ => {
+            Err(ParseError::InvalidNumber {
+                rule: name.to_owned(),
+                argument: argument.to_owned(),
+            })
+        }
+    }
+}
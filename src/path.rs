@@ -0,0 +1,130 @@
+use params::{Map, Value};
+
+/// Traverses nested `Map`s and `Array`s to find the value at the given path.
+///
+/// Unlike `Map::find`, numeric segments may be used to index into arrays,
+/// so `["items", "0", "name"]` will find the name of the first item.
+pub fn find<'a>(values: &'a Map, path: &[&str]) -> Option<&'a Value> {
+    if path.is_empty() {
+        return None;
+    }
+
+    let mut value = values.get(path[0]);
+    for segment in &path[1..] {
+        value = match value {
+            Some(&Value::Map(ref map)) => map.get(*segment),
+            Some(&Value::Array(ref array)) => {
+                match segment.parse::<usize>() {
+                    Ok(index) => array.get(index),
+                    Err(_) => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    value
+}
+
+/// Stores `value` at the given path, creating intermediate maps where needed.
+///
+/// Array elements may be replaced by index, but arrays are never extended.
+pub fn assign(values: &mut Map, path: &[&str], value: Value) {
+    let (last, parents) = match path.split_last() {
+        Some(split) => split,
+        None => return,
+    };
+
+    let mut map = values;
+    for (i, segment) in parents.iter().enumerate() {
+        let next = map.entry(segment.to_string()).or_insert_with(|| Value::Map(Map::new()));
+        match *next {
+            Value::Map(ref mut inner) => map = inner,
+            Value::Array(ref mut array) => {
+                assign_array(array, &path[i + 1..], value);
+                return;
+            }
+            _ => return,
+        }
+    }
+    map.insert(last.to_string(), value);
+}
+
+fn assign_array(array: &mut [Value], path: &[&str], value: Value) {
+    let index = match path[0].parse::<usize>() {
+        Ok(index) if index < array.len() => index,
+        _ => return,
+    };
+    if path.len() == 1 {
+        array[index] = value;
+        return;
+    }
+    match array[index] {
+        Value::Map(ref mut map) => assign(map, &path[1..], value),
+        Value::Array(ref mut inner) => assign_array(inner, &path[1..], value),
+        _ => (),
+    }
+}
+
+/// Expands each `*` segment of `pattern` into the indices or keys
+/// actually present in `values`, returning every concrete path.
+///
+/// A pattern without wildcards is returned unchanged, whether or not it exists.
+pub fn expand(values: &Map, pattern: &[&str]) -> Vec<Vec<String>> {
+    let mut paths = vec![Vec::new()];
+    for segment in pattern {
+        if *segment != "*" {
+            for path in &mut paths {
+                path.push(segment.to_string());
+            }
+            continue;
+        }
+
+        let mut expanded = Vec::new();
+        for path in paths {
+            let keys = if path.is_empty() {
+                values.keys().cloned().collect()
+            } else {
+                match find(values, &segments(&path)) {
+                    Some(&Value::Map(ref map)) => map.keys().cloned().collect(),
+                    Some(&Value::Array(ref array)) => {
+                        (0..array.len()).map(|index| index.to_string()).collect()
+                    }
+                    _ => Vec::new(),
+                }
+            };
+            for key in keys {
+                let mut concrete = path.clone();
+                concrete.push(key);
+                expanded.push(concrete);
+            }
+        }
+        paths = expanded;
+    }
+    paths
+}
+
+/// Resolves a dotted `other` field relative to a concrete path.
+///
+/// Each `*` in `other` is replaced, in order, by the index that the matching
+/// `*` in `pattern` was expanded to in `concrete`. For example, resolving
+/// `items.*.confirm` against the pattern `items.*.price` and the concrete
+/// path `items.2.price` gives `items.2.confirm`.
+pub fn resolve(other: &str, pattern: &[&str], concrete: &[String]) -> Vec<String> {
+    let mut bindings = pattern.iter()
+        .zip(concrete)
+        .filter(|&(segment, _)| *segment == "*")
+        .map(|(_, index)| index);
+    other.split('.')
+        .map(|segment| if segment == "*" {
+                 bindings.next().map(|index| index.as_str()).unwrap_or(segment).to_string()
+             } else {
+                 segment.to_string()
+             })
+        .collect()
+}
+
+/// Borrows a concrete path as the string slices expected by the validators.
+pub fn segments(path: &[String]) -> Vec<&str> {
+    path.iter().map(|segment| segment.as_str()).collect()
+}
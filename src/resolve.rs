@@ -0,0 +1,515 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use dns_lookup;
+use rand;
+
+lazy_static! {
+    /// The resolver used when `Options` does not set one, which is shared so that
+    /// its cache lasts between calls to `validate`.
+    static ref DEFAULT_RESOLVER: CachingResolver<SystemResolver> =
+        CachingResolver::new(SystemResolver::new());
+
+    /// The queue of addresses for the lookup threads to look up.
+    static ref LOOKUPS: Mutex<mpsc::SyncSender<Lookup>> = Mutex::new(start_lookup_threads());
+}
+
+/// The number of threads which look up addresses with the operating system's resolver.
+const LOOKUP_THREADS: usize = 4;
+
+/// The number of lookups which may wait for a lookup thread, after which more are refused.
+const LOOKUP_QUEUE: usize = 64;
+
+/// Records cached by a `CachingResolver` for a host and type of record, with when they expire.
+type Cache = HashMap<(String, RecordType), (Instant, Vec<String>)>;
+
+/// An address for a lookup thread to look up, and where to send the addresses it finds.
+struct Lookup {
+    host: String,
+    /// Set when the lookup has timed out, so that it need not be started.
+    cancelled: Arc<AtomicBool>,
+    reply: mpsc::Sender<Result<Vec<IpAddr>, String>>,
+}
+
+/// A type of DNS record which a `Resolver` can look up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RecordType {
+    /// An IPv4 address, as used to reach a website.
+    A,
+    /// An IPv6 address, as used to reach a website.
+    Aaaa,
+    /// A mail exchanger, which accepts e-mail for a domain.
+    Mx,
+}
+
+/// An error encountered while looking up DNS records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+    /// The lookup did not finish within the resolver's timeout.
+    Timeout,
+    /// No name server is configured to query, as there is no `/etc/resolv.conf`
+    /// or it names none, and `SystemResolver::nameserver` was not set.
+    NoNameserver,
+    /// The lookup failed, such as when no name server could be reached.
+    Failed(String),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResolveError::Timeout => f.write_str("DNS lookup timed out"),
+            ResolveError::NoNameserver => {
+                f.write_str("DNS lookup failed: no name server is configured in /etc/resolv.conf")
+            }
+            ResolveError::Failed(ref reason) => write!(f, "DNS lookup failed: {}", reason),
+        }
+    }
+}
+
+impl Error for ResolveError {}
+
+/// A source of DNS records for the `ActiveUrl` rule and `EmailOptions::dns`.
+///
+/// By default, records are looked up with a `SystemResolver`, whose results are cached
+/// by a `CachingResolver`. Another resolver can be set with `Options::resolver`,
+/// such as a `StaticResolver` in tests which should not depend on the network.
+pub trait Resolver: Send + Sync {
+    /// Returns the records of type `record` for `host`, which are IP addresses
+    /// for `A` and `AAAA` records, and the names of mail exchangers for `MX` records.
+    ///
+    /// A host which does not exist should have no records, rather than an error.
+    /// Either way, a field whose host has no records fails validation.
+    fn resolve(&self, host: &str, record: RecordType) -> Result<Vec<String>, ResolveError>;
+}
+
+/// Returns the resolver to use when `Options` does not set one.
+pub fn default_resolver() -> &'static dyn Resolver {
+    &*DEFAULT_RESOLVER
+}
+
+/// Whether `host` has an IPv4 or IPv6 address.
+pub fn has_address(resolver: &dyn Resolver, host: &str) -> bool {
+    [RecordType::A, RecordType::Aaaa].iter().any(|&record| {
+        resolver.resolve(host, record).map(|records| !records.is_empty()) == Ok(true)
+    })
+}
+
+/// A resolver which answers from a fixed set of records, for tests and offline use.
+///
+/// Hosts are compared case-insensitively, with any trailing dot ignored.
+#[derive(Debug, Clone, Default)]
+pub struct StaticResolver {
+    records: BTreeMap<(String, RecordType), Vec<String>>,
+}
+
+impl StaticResolver {
+    /// Creates a resolver without any records.
+    pub fn new() -> StaticResolver {
+        StaticResolver::default()
+    }
+
+    /// Adds a record of type `record` for `host`, such as an IP address for `RecordType::A`,
+    /// or the name of a mail exchanger for `RecordType::Mx`.
+    pub fn record<H, V>(mut self, host: H, record: RecordType, value: V) -> StaticResolver
+        where H: AsRef<str>,
+              V: Into<String>
+    {
+        self.records
+            .entry((normalize_host(host.as_ref()), record))
+            .or_default()
+            .push(value.into());
+        self
+    }
+}
+
+impl Resolver for StaticResolver {
+    fn resolve(&self, host: &str, record: RecordType) -> Result<Vec<String>, ResolveError> {
+        Ok(self.records.get(&(normalize_host(host), record)).cloned().unwrap_or_default())
+    }
+}
+
+/// A resolver which caches the records found by another resolver for a time.
+///
+/// Failed lookups are not cached, so a host is looked up again after a timeout.
+#[derive(Debug)]
+pub struct CachingResolver<R> {
+    resolver: R,
+    ttl: Duration,
+    capacity: usize,
+    cache: Mutex<Cache>,
+}
+
+impl<R: Resolver> CachingResolver<R> {
+    /// Creates a cache of up to 256 results from `resolver`, which are kept for five minutes.
+    pub fn new(resolver: R) -> CachingResolver<R> {
+        CachingResolver {
+            resolver,
+            ttl: Duration::from_secs(300),
+            capacity: 256,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how long results are kept. Defaults to five minutes.
+    pub fn ttl(mut self, ttl: Duration) -> CachingResolver<R> {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets how many results are kept, after which the oldest result is replaced.
+    /// Defaults to 256.
+    pub fn capacity(mut self, capacity: usize) -> CachingResolver<R> {
+        self.capacity = capacity;
+        self
+    }
+}
+
+impl<R: Resolver> Resolver for CachingResolver<R> {
+    fn resolve(&self, host: &str, record: RecordType) -> Result<Vec<String>, ResolveError> {
+        let key = (normalize_host(host), record);
+        let now = Instant::now();
+        {
+            let cache = self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Some(&(expires, ref records)) = cache.get(&key) {
+                if expires > now {
+                    return Ok(records.clone());
+                }
+            }
+        }
+        // Look up outside the lock, so that a slow lookup does not hold up others
+        let records = self.resolver.resolve(host, record)?;
+        if self.capacity > 0 {
+            let mut cache = self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            cache.retain(|_, &mut (expires, _)| expires > now);
+            if cache.len() >= self.capacity && !cache.contains_key(&key) {
+                let oldest = cache.iter()
+                    .min_by_key(|&(_, &(expires, _))| expires)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    cache.remove(&oldest);
+                }
+            }
+            cache.insert(key, (now + self.ttl, records.clone()));
+        }
+        Ok(records)
+    }
+}
+
+/// A resolver which looks up records on the network.
+///
+/// Addresses are looked up with the operating system's resolver, so names in the hosts file
+/// are found, while mail exchangers are queried from a name server over UDP, and over TCP
+/// if the answer is too large for UDP.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemResolver {
+    timeout: Duration,
+    nameserver: Option<SocketAddr>,
+}
+
+impl Default for SystemResolver {
+    fn default() -> SystemResolver {
+        SystemResolver {
+            timeout: Duration::from_secs(5),
+            nameserver: None,
+        }
+    }
+}
+
+impl SystemResolver {
+    /// Creates a resolver with a timeout of five seconds, which queries the first name server
+    /// in `/etc/resolv.conf`.
+    pub fn new() -> SystemResolver {
+        SystemResolver::default()
+    }
+
+    /// Sets how long to wait for each lookup. Defaults to five seconds.
+    ///
+    /// The operating system's resolver cannot be interrupted, so a lookup of an address
+    /// which times out carries on in the background until it finishes. Addresses are looked up
+    /// on a fixed number of threads shared by every `SystemResolver`, and a lookup fails
+    /// without waiting if too many others are queued for them.
+    pub fn timeout(mut self, timeout: Duration) -> SystemResolver {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the name server to query for mail exchangers, such as `8.8.8.8:53`.
+    ///
+    /// This must be set where there is no `/etc/resolv.conf`, such as on Windows,
+    /// or looking up mail exchangers fails with `ResolveError::NoNameserver`.
+    pub fn nameserver(mut self, nameserver: SocketAddr) -> SystemResolver {
+        self.nameserver = Some(nameserver);
+        self
+    }
+
+    fn lookup_addresses(&self,
+                        host: &str,
+                        record: RecordType)
+                        -> Result<Vec<String>, ResolveError> {
+        let (reply, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let lookup = Lookup {
+            host: host.to_owned(),
+            cancelled: cancelled.clone(),
+            reply,
+        };
+        let lookups = LOOKUPS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        match lookups.try_send(lookup) {
+            Ok(()) => (),
+            Err(mpsc::TrySendError::Full(_)) => {
+                return Err(ResolveError::Failed("too many lookups are waiting".to_owned()))
+            }
+            Err(mpsc::TrySendError::Disconnected(_)) => {
+                return Err(ResolveError::Failed("the lookup threads have stopped".to_owned()))
+            }
+        }
+        match receiver.recv_timeout(self.timeout) {
+            Ok(Ok(addresses)) => {
+                Ok(addresses.into_iter()
+                    .filter(|address| address.is_ipv4() == (record == RecordType::A))
+                    .map(|address| address.to_string())
+                    .collect())
+            }
+            Ok(Err(reason)) => Err(ResolveError::Failed(reason)),
+            Err(_) => {
+                // Let a thread which has not started the lookup skip it
+                cancelled.store(true, Ordering::SeqCst);
+                Err(ResolveError::Timeout)
+            }
+        }
+    }
+
+    fn query_mx(&self, host: &str) -> Result<Vec<String>, ResolveError> {
+        let nameserver = match self.nameserver {
+            Some(nameserver) => nameserver,
+            None => system_nameserver()?,
+        };
+        let deadline = Instant::now() + self.timeout;
+        let query = mx_query(rand::random(), host)?;
+        let mut response = query_udp(nameserver, &query, deadline)?;
+        // A truncated answer is missing records, so ask again over TCP, as in RFC 7766
+        if response.get(2).is_some_and(|flags| flags & 0x02 != 0) {
+            response = query_tcp(nameserver, &query, deadline)?;
+        }
+        parse_mx(&response).ok_or_else(|| ResolveError::Failed("malformed response".to_owned()))?
+    }
+}
+
+impl Resolver for SystemResolver {
+    fn resolve(&self, host: &str, record: RecordType) -> Result<Vec<String>, ResolveError> {
+        match record {
+            RecordType::A | RecordType::Aaaa => self.lookup_addresses(host, record),
+            RecordType::Mx => self.query_mx(host),
+        }
+    }
+}
+
+fn normalize_host(host: &str) -> String {
+    host.trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Converts an error from a socket, which is a timeout if the socket's timeout ran out.
+fn io_error(err: io::Error) -> ResolveError {
+    match err.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => ResolveError::Timeout,
+        _ => ResolveError::Failed(err.to_string()),
+    }
+}
+
+/// Returns the time left until `deadline`, or a timeout if there is none.
+fn remaining(deadline: Instant) -> Result<Duration, ResolveError> {
+    match deadline.saturating_duration_since(Instant::now()) {
+        remaining if remaining == Duration::from_secs(0) => Err(ResolveError::Timeout),
+        remaining => Ok(remaining),
+    }
+}
+
+/// Starts the threads which look up addresses with the operating system's resolver,
+/// returning the queue of lookups for them.
+fn start_lookup_threads() -> mpsc::SyncSender<Lookup> {
+    let (sender, receiver) = mpsc::sync_channel::<Lookup>(LOOKUP_QUEUE);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..LOOKUP_THREADS {
+        let receiver = receiver.clone();
+        thread::Builder::new()
+            .name("iron_valid-resolver".to_owned())
+            .spawn(move || loop {
+                let lookup = {
+                    let receiver = receiver.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    match receiver.recv() {
+                        Ok(lookup) => lookup,
+                        Err(_) => return,
+                    }
+                };
+                if lookup.cancelled.load(Ordering::SeqCst) {
+                    continue;
+                }
+                let addresses = dns_lookup::lookup_host(&lookup.host)
+                    .map(|addresses| addresses.filter_map(Result::ok).collect::<Vec<IpAddr>>())
+                    .map_err(|_| format!("could not resolve \"{}\"", lookup.host));
+                lookup.reply.send(addresses).ok();
+            })
+            .expect("Failed to start a DNS lookup thread");
+    }
+    sender
+}
+
+/// Sends `query` to `nameserver` over UDP, and returns the response with the same ID.
+fn query_udp(nameserver: SocketAddr,
+             query: &[u8],
+             deadline: Instant)
+             -> Result<Vec<u8>, ResolveError> {
+    let local = if nameserver.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+    let socket = UdpSocket::bind(local).map_err(io_error)?;
+    socket.send_to(query, nameserver).map_err(io_error)?;
+    let mut buffer = [0u8; 4096];
+    loop {
+        socket.set_read_timeout(Some(remaining(deadline)?)).map_err(io_error)?;
+        let (len, from) = socket.recv_from(&mut buffer).map_err(io_error)?;
+        // Ignore stray packets, which are not the answer to this query
+        if from == nameserver && len >= 2 && buffer[..2] == query[..2] {
+            return Ok(buffer[..len].to_vec());
+        }
+    }
+}
+
+/// Sends `query` to `nameserver` over TCP, where each message is preceded by its length.
+fn query_tcp(nameserver: SocketAddr,
+             query: &[u8],
+             deadline: Instant)
+             -> Result<Vec<u8>, ResolveError> {
+    let mut stream = TcpStream::connect_timeout(&nameserver, remaining(deadline)?)
+        .map_err(io_error)?;
+    stream.set_write_timeout(Some(remaining(deadline)?)).map_err(io_error)?;
+    let mut message = (query.len() as u16).to_be_bytes().to_vec();
+    message.extend_from_slice(query);
+    stream.write_all(&message).map_err(io_error)?;
+    let mut read = |len: usize| -> Result<Vec<u8>, ResolveError> {
+        let mut buffer = vec![0u8; len];
+        stream.set_read_timeout(Some(remaining(deadline)?)).map_err(io_error)?;
+        stream.read_exact(&mut buffer).map_err(io_error)?;
+        Ok(buffer)
+    };
+    let len = read(2)?;
+    let response = read(usize::from(len[0]) << 8 | usize::from(len[1]))?;
+    if response.get(..2) != Some(&query[..2]) {
+        return Err(ResolveError::Failed("mismatched response".to_owned()));
+    }
+    Ok(response)
+}
+
+/// Returns the address of the first name server in `/etc/resolv.conf`.
+fn system_nameserver() -> Result<SocketAddr, ResolveError> {
+    let config = fs::read_to_string("/etc/resolv.conf").map_err(|_| ResolveError::NoNameserver)?;
+    config.lines()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some("nameserver"), Some(address)) => {
+                    // Drop any IPv6 zone, such as `%eth0`, which `IpAddr` cannot parse
+                    address.split('%').next().unwrap_or("").parse::<IpAddr>().ok()
+                }
+                _ => None,
+            }
+        })
+        .map(|address| SocketAddr::new(address, 53))
+        .next()
+        .ok_or(ResolveError::NoNameserver)
+}
+
+/// Builds a recursive DNS query for the `MX` records of `host`.
+fn mx_query(id: u16, host: &str) -> Result<Vec<u8>, ResolveError> {
+    let mut query = Vec::with_capacity(host.len() + 18);
+    query.extend_from_slice(&id.to_be_bytes());
+    // Recursion desired, then one question and no other records
+    query.extend_from_slice(&[0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in host.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(ResolveError::Failed(format!("\"{}\" is not a domain name", host)));
+        }
+        query.push(label.len() as u8);
+        query.extend_from_slice(label.as_bytes());
+    }
+    query.push(0);
+    // QTYPE is MX, and QCLASS is IN
+    query.extend_from_slice(&[0, 15, 0, 1]);
+    Ok(query)
+}
+
+/// Returns the mail exchangers in the DNS response `message`, or an error
+/// if the name server could not answer.
+fn parse_mx(message: &[u8]) -> Option<Result<Vec<String>, ResolveError>> {
+    let read_u16 = |offset: usize| -> Option<usize> {
+        Some(usize::from(*message.get(offset)?) << 8 | usize::from(*message.get(offset + 1)?))
+    };
+    match read_u16(2)? & 0x000f {
+        0 => (),
+        // The name does not exist
+        3 => return Some(Ok(Vec::new())),
+        code => return Some(Err(ResolveError::Failed(format!("name server error {}", code)))),
+    }
+    let questions = read_u16(4)?;
+    let answers = read_u16(6)?;
+    let mut offset = 12;
+    for _ in 0..questions {
+        offset = skip_name(message, offset)? + 4;
+    }
+    let mut exchanges = Vec::new();
+    for _ in 0..answers {
+        offset = skip_name(message, offset)?;
+        let kind = read_u16(offset)?;
+        let len = read_u16(offset + 8)?;
+        offset += 10;
+        if kind == 15 {
+            exchanges.push(read_name(message, offset + 2)?);
+        }
+        offset += len;
+    }
+    Some(Ok(exchanges))
+}
+
+/// Returns the offset just past the name at `offset` in `message`.
+fn skip_name(message: &[u8], mut offset: usize) -> Option<usize> {
+    loop {
+        match *message.get(offset)? {
+            0 => return Some(offset + 1),
+            // A pointer to the rest of the name elsewhere
+            len if len & 0xc0 == 0xc0 => return Some(offset + 2),
+            len => offset += usize::from(len) + 1,
+        }
+    }
+}
+
+/// Reads the name at `offset` in `message`, following compression pointers,
+/// without the trailing dot.
+fn read_name(message: &[u8], mut offset: usize) -> Option<String> {
+    let mut labels = Vec::new();
+    // Each pointer must go backwards, which rules out loops
+    let mut limit = offset;
+    loop {
+        match *message.get(offset)? {
+            0 => return Some(labels.join(".")),
+            len if len & 0xc0 == 0xc0 => {
+                let target = usize::from(len & 0x3f) << 8 | usize::from(*message.get(offset + 1)?);
+                if target >= limit {
+                    return None;
+                }
+                limit = target;
+                offset = target;
+            }
+            len => {
+                let label = message.get(offset + 1..offset + 1 + usize::from(len))?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                offset += usize::from(len) + 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use iron::mime::Mime;
+
+/// The number of bytes read from the start of a file to identify its type.
+/// Binary types are identified from the first few bytes, but an SVG document may have
+/// a long XML declaration, doctype, or comments before its `svg` element.
+const SNIFF_LEN: u64 = 8192;
+
+/// Known file types, as the MIME type, its extensions, and the type which
+/// the contents of such a file are identified as by `sniff`, if any.
+///
+/// Office documents are identified by their container format,
+/// and text formats cannot be identified at all.
+const TYPES: &[(&str, &[&str], Option<&str>)] = &[
+    ("image/jpeg", &["jpg", "jpeg", "jpe"], Some("image/jpeg")),
+    ("image/png", &["png"], Some("image/png")),
+    ("image/gif", &["gif"], Some("image/gif")),
+    ("image/bmp", &["bmp"], Some("image/bmp")),
+    ("image/webp", &["webp"], Some("image/webp")),
+    ("image/svg+xml", &["svg"], Some("image/svg+xml")),
+    ("image/tiff", &["tif", "tiff"], Some("image/tiff")),
+    ("image/x-icon", &["ico"], Some("image/x-icon")),
+    ("application/pdf", &["pdf"], Some("application/pdf")),
+    ("application/zip", &["zip"], Some("application/zip")),
+    ("application/gzip", &["gz"], Some("application/gzip")),
+    ("application/x-7z-compressed", &["7z"], Some("application/x-7z-compressed")),
+    ("application/vnd.rar", &["rar"], Some("application/vnd.rar")),
+    ("application/msword", &["doc"], Some("application/x-ole-storage")),
+    ("application/vnd.ms-excel", &["xls"], Some("application/x-ole-storage")),
+    ("application/vnd.ms-powerpoint", &["ppt"], Some("application/x-ole-storage")),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+     &["docx"],
+     Some("application/zip")),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+     &["xlsx"],
+     Some("application/zip")),
+    ("application/vnd.openxmlformats-officedocument.presentationml.presentation",
+     &["pptx"],
+     Some("application/zip")),
+    ("application/vnd.oasis.opendocument.text", &["odt"], Some("application/zip")),
+    ("application/vnd.oasis.opendocument.spreadsheet", &["ods"], Some("application/zip")),
+    ("audio/mpeg", &["mp3"], Some("audio/mpeg")),
+    ("audio/wav", &["wav"], Some("audio/wav")),
+    ("audio/ogg", &["ogg", "oga"], Some("audio/ogg")),
+    ("video/mp4", &["mp4", "m4v"], Some("video/mp4")),
+    ("text/plain", &["txt"], None),
+    ("text/csv", &["csv"], None),
+    ("text/html", &["html", "htm"], None),
+    ("application/json", &["json"], None),
+    ("application/xml", &["xml"], None),
+];
+
+/// Returns the MIME type of `mime`, without any parameters, in lowercase.
+pub fn essence(mime: &Mime) -> String {
+    format!("{}/{}", mime.0, mime.1).to_lowercase()
+}
+
+/// Returns the MIME type for the file extension `extension`, if it is known.
+pub fn from_extension(extension: &str) -> Option<&'static str> {
+    TYPES.iter()
+        .find(|&&(_, extensions, _)| {
+            extensions.iter().any(|known| known.eq_ignore_ascii_case(extension))
+        })
+        .map(|&(mime, _, _)| mime)
+}
+
+/// Whether the contents of a file, identified as `sniffed`, agree with its declared
+/// MIME type `mime`. A type which is not known agrees with any contents.
+pub fn agrees(mime: &str, sniffed: Option<&str>) -> bool {
+    match TYPES.iter().find(|&&(known, _, _)| known == mime) {
+        Some(&(_, _, expected)) => expected == sniffed,
+        None => true,
+    }
+}
+
+/// Identifies the type of the file at `path` from the magic bytes at its start,
+/// returning `None` if the contents are not of a known type.
+pub fn sniff(path: &Path) -> io::Result<Option<&'static str>> {
+    let mut bytes = Vec::new();
+    fs::File::open(path)?.take(SNIFF_LEN).read_to_end(&mut bytes)?;
+    Ok(sniff_bytes(&bytes))
+}
+
+fn sniff_bytes(bytes: &[u8]) -> Option<&'static str> {
+    let riff = |kind: &[u8]| bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(kind);
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if bytes.starts_with(b"\xff\xd8\xff") {
+        Some("image/jpeg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if is_bmp(bytes) {
+        Some("image/bmp")
+    } else if riff(b"WEBP") {
+        Some("image/webp")
+    } else if bytes.starts_with(b"II*\x00") || bytes.starts_with(b"MM\x00*") {
+        Some("image/tiff")
+    } else if is_ico(bytes) {
+        Some("image/x-icon")
+    } else if bytes.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else if bytes.starts_with(b"PK\x03\x04") || bytes.starts_with(b"PK\x05\x06") {
+        Some("application/zip")
+    } else if bytes.starts_with(b"\x1f\x8b") {
+        Some("application/gzip")
+    } else if bytes.starts_with(b"7z\xbc\xaf\x27\x1c") {
+        Some("application/x-7z-compressed")
+    } else if bytes.starts_with(b"Rar!\x1a\x07") {
+        Some("application/vnd.rar")
+    } else if bytes.starts_with(b"\xd0\xcf\x11\xe0\xa1\xb1\x1a\xe1") {
+        Some("application/x-ole-storage")
+    } else if is_mp3(bytes) {
+        Some("audio/mpeg")
+    } else if riff(b"WAVE") {
+        Some("audio/wav")
+    } else if bytes.starts_with(b"OggS") {
+        Some("audio/ogg")
+    } else if is_mp4(bytes) {
+        Some("video/mp4")
+    } else if is_svg(bytes) {
+        Some("image/svg+xml")
+    } else {
+        None
+    }
+}
+
+/// Whether `bytes` begin a BMP file, whose reserved fields are zero and whose header
+/// is one of the known sizes, so that text starting with `BM` is not mistaken for one.
+fn is_bmp(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"BM") && bytes.get(6..10) == Some(&[0, 0, 0, 0]) &&
+    match bytes.get(14..18) {
+        Some(&[size, 0, 0, 0]) => [12, 40, 52, 56, 64, 108, 124].contains(&size),
+        _ => false,
+    }
+}
+
+/// Whether `bytes` begin an ICO file with at least one image, whose first entry
+/// has its reserved byte set to zero.
+fn is_ico(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"\x00\x00\x01\x00") &&
+    bytes.get(4..6).is_some_and(|count| count != [0, 0]) && bytes.get(9) == Some(&0)
+}
+
+/// Whether `bytes` begin an MP3 file, with either an ID3v2 tag or the header
+/// of an MPEG-1, MPEG-2, or MPEG-2.5 Layer III frame.
+fn is_mp3(bytes: &[u8]) -> bool {
+    if bytes.starts_with(b"ID3") {
+        // The major version, a revision, flags, and a size in four 7-bit bytes
+        return match bytes.get(3..10) {
+            Some(header) => {
+                (2..5).contains(&header[0]) && header[1] != 0xff &&
+                header[3..].iter().all(|&byte| byte < 0x80)
+            }
+            None => false,
+        };
+    }
+    match bytes.get(..3) {
+        Some(&[0xff, version, flags]) => {
+            [0xfb, 0xfa, 0xf3, 0xf2, 0xe3, 0xe2].contains(&version) &&
+            // Neither the bitrate nor the sample rate may be the reserved value
+            flags >> 4 != 0x0f && flags & 0x0c != 0x0c
+        }
+        _ => false,
+    }
+}
+
+/// Whether `bytes` begin an MP4 file, with an `ftyp` box of a plausible size.
+fn is_mp4(bytes: &[u8]) -> bool {
+    match bytes.get(..8) {
+        Some(&[a, b, c, d, b'f', b't', b'y', b'p']) => {
+            let size = u32::from_be_bytes([a, b, c, d]);
+            size >= 16 && size % 4 == 0 && size <= 4096
+        }
+        _ => false,
+    }
+}
+
+/// Whether `bytes` begin an SVG document, whose root element is `svg`,
+/// allowing for an XML declaration, comments, and a doctype before it.
+fn is_svg(bytes: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(bytes);
+    let mut text = text.trim_start_matches('\u{feff}').trim_start();
+    loop {
+        let end = if text.starts_with("<?") {
+            text.find("?>").map(|end| end + 2)
+        } else if text.starts_with("<!--") {
+            text.find("-->").map(|end| end + 3)
+        } else if text.starts_with("<!") {
+            text.find('>').map(|end| end + 1)
+        } else {
+            break;
+        };
+        match end {
+            Some(end) => text = text[end..].trim_start(),
+            None => return false,
+        }
+    }
+    text.starts_with("<svg") &&
+    text[4..].starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/')
+}
@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+lazy_static! {
+    /// The built-in English messages.
+    pub static ref ENGLISH: Catalog = Catalog::from_json("en", include_str!("locales/en.json"))
+        .expect("Invalid built-in English catalog");
+}
+
+/// A source of message templates in one or more locales.
+///
+/// Templates are looked up by message key, which is the code of the rule,
+/// such as `required`, or for rules whose message depends on the type of the value,
+/// the code followed by `string`, `numeric`, `array`, or `file`, such as `max.string`.
+/// Templates may contain the same placeholders as custom messages set in `Options`.
+pub trait Translator: Send + Sync {
+    /// Returns the template for `key` in `locale`, or `None` if there is no translation.
+    ///
+    /// `count` is the number that the message should agree with, such as the limit
+    /// for `Max`, which can be used to choose a plural form with `plural_category`.
+    fn translate(&self, locale: &str, key: &str, count: Option<f64>) -> Option<String>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Entry {
+    Message(String),
+    Plural(BTreeMap<String, String>),
+}
+
+/// A catalog of message templates, which can be loaded from JSON.
+///
+/// Each locale is a JSON object mapping message keys to templates.
+/// A template may instead be an object mapping plural categories
+/// (`zero`, `one`, `two`, `few`, `many`, and `other`) to templates,
+/// in which case the form is chosen with `plural_category`, falling back to `other`:
+///
+/// ```json
+/// {
+///     "required": "Das Feld :attribute ist erforderlich.",
+///     "max.array": {
+///         "one": "Das Feld :attribute darf höchstens :max Element haben.",
+///         "other": "Das Feld :attribute darf höchstens :max Elemente haben."
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    locales: BTreeMap<String, BTreeMap<String, Entry>>,
+}
+
+impl Catalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Catalog {
+        Catalog::default()
+    }
+
+    /// Returns a catalog containing the built-in English messages for the `en` locale,
+    /// which may be used as a starting point for overriding them.
+    pub fn english() -> Catalog {
+        ENGLISH.clone()
+    }
+
+    /// Creates a catalog from the JSON messages for `locale`.
+    pub fn from_json(locale: &str, json: &str) -> Result<Catalog, CatalogError> {
+        let mut catalog = Catalog::new();
+        catalog.load_json(locale, json)?;
+        Ok(catalog)
+    }
+
+    /// Adds the JSON messages for `locale`, replacing any existing messages with the same key.
+    pub fn load_json(&mut self, locale: &str, json: &str) -> Result<(), CatalogError> {
+        let parsed = serde_json::from_str::<serde_json::Value>(json).map_err(CatalogError::Json)?;
+        let object = parsed.as_object().ok_or(CatalogError::NotAnObject)?;
+        let messages = self.locales.entry(locale.to_owned()).or_default();
+        for (key, value) in object {
+            let entry = match *value {
+                serde_json::Value::String(ref message) => Entry::Message(message.clone()),
+                serde_json::Value::Object(ref forms) => {
+                    let mut plural = BTreeMap::new();
+                    for (category, message) in forms {
+                        match message.as_str() {
+                            Some(message) => plural.insert(category.clone(), message.to_owned()),
+                            None => return Err(CatalogError::InvalidEntry(key.clone())),
+                        };
+                    }
+                    Entry::Plural(plural)
+                }
+                _ => return Err(CatalogError::InvalidEntry(key.clone())),
+            };
+            messages.insert(key.clone(), entry);
+        }
+        Ok(())
+    }
+
+    /// Adds a single template for `key` in `locale`.
+    pub fn insert<K, V>(&mut self, locale: &str, key: K, message: V)
+        where K: Into<String>,
+              V: Into<String>
+    {
+        self.locales
+            .entry(locale.to_owned())
+            .or_default()
+            .insert(key.into(), Entry::Message(message.into()));
+    }
+}
+
+impl Translator for Catalog {
+    fn translate(&self, locale: &str, key: &str, count: Option<f64>) -> Option<String> {
+        match self.locales.get(locale).and_then(|messages| messages.get(key)) {
+            Some(&Entry::Message(ref message)) => Some(message.clone()),
+            Some(&Entry::Plural(ref forms)) => {
+                let category = plural_category(locale, count.unwrap_or(0f64));
+                forms.get(category).or_else(|| forms.get("other")).cloned()
+            }
+            None => None,
+        }
+    }
+}
+
+/// An error encountered while loading a `Catalog`.
+#[derive(Debug)]
+pub enum CatalogError {
+    /// The catalog is not valid JSON.
+    Json(serde_json::Error),
+    /// The catalog is not a JSON object.
+    NotAnObject,
+    /// The entry for the given key is neither a string nor an object of strings.
+    InvalidEntry(String),
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CatalogError::Json(ref err) => write!(f, "Invalid catalog: {}", err),
+            CatalogError::NotAnObject => f.write_str("Invalid catalog: expected a JSON object"),
+            CatalogError::InvalidEntry(ref key) => {
+                write!(f, "Invalid catalog entry for \"{}\"", key)
+            }
+        }
+    }
+}
+
+impl Error for CatalogError {}
+
+/// Returns the CLDR plural category of `count` for the language of `locale`,
+/// which is one of `zero`, `one`, `two`, `few`, `many`, or `other`.
+///
+/// Rules are included for the most common languages; other languages
+/// use the English rule, where only exactly `1` is `one`.
+pub fn plural_category(locale: &str, count: f64) -> &'static str {
+    let mut subtags = locale.split(&['-', '_'][..]);
+    let language = subtags.next().unwrap_or("").to_lowercase();
+    let region = subtags.next().unwrap_or("").to_uppercase();
+    let integer = count.fract() == 0f64 && count >= 0f64;
+    let i = count.abs().trunc() as u64;
+    match language.as_str() {
+        "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "tr" => "other",
+        "fr" => if i <= 1 { "one" } else { "other" },
+        "pt" if region == "PT" => {
+            if integer && i == 1 { "one" } else { "other" }
+        }
+        "pt" => if i <= 1 { "one" } else { "other" },
+        "ru" | "uk" | "be" => {
+            if !integer {
+                "other"
+            } else if i % 10 == 1 && i % 100 != 11 {
+                "one"
+            } else if (2..5).contains(&(i % 10)) && !(12..15).contains(&(i % 100)) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        "pl" => {
+            if !integer {
+                "other"
+            } else if i == 1 {
+                "one"
+            } else if (2..5).contains(&(i % 10)) && !(12..15).contains(&(i % 100)) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        "cs" | "sk" => {
+            if !integer {
+                "many"
+            } else if i == 1 {
+                "one"
+            } else if (2..5).contains(&i) {
+                "few"
+            } else {
+                "other"
+            }
+        }
+        "ar" => {
+            if !integer {
+                "other"
+            } else if i == 0 {
+                "zero"
+            } else if i == 1 {
+                "one"
+            } else if i == 2 {
+                "two"
+            } else if (3..11).contains(&(i % 100)) {
+                "few"
+            } else if (11..100).contains(&(i % 100)) {
+                "many"
+            } else {
+                "other"
+            }
+        }
+        _ => if integer && i == 1 { "one" } else { "other" },
+    }
+}
+
+/// Returns the locales to try for `locale`, from most to least specific,
+/// ending with `en`. For example, `pt-BR` gives `pt-BR`, `pt`, and `en`.
+pub fn fallback_chain(locale: &str) -> Vec<String> {
+    let mut chain = Vec::new();
+    let mut current = locale.replace('_', "-");
+    while !current.is_empty() {
+        chain.push(current.clone());
+        current = match current.rfind('-') {
+            Some(index) => current[..index].to_owned(),
+            None => String::new(),
+        };
+    }
+    if !chain.iter().any(|locale| locale == "en") {
+        chain.push("en".to_owned());
+    }
+    chain
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use error::ValidationError;
+use params::{Map, Value};
+use path;
+
+/// A user-defined rule, which can be added to a ruleset with `Rule::custom`.
+///
+/// Custom rules run in order with the built-in rules for a field, so they see any values
+/// transformed by earlier rules, and their errors are reported in the same error map.
+pub trait Validator: Send + Sync {
+    /// Validates the field at `path`, which is the concrete path of the field,
+    /// such as `["items", "2", "sku"]`.
+    ///
+    /// Returns `Ok(Some(value))` to replace the field with `value`,
+    /// `Ok(None)` to leave it as it is, or a `ValidationError` if it is invalid.
+    /// Errors for custom rules will usually use `ErrorKind::Custom`.
+    fn validate(&self, ctx: &Context, path: &[&str]) -> Result<Option<Value>, ValidationError>;
+}
+
+impl<'a> fmt::Debug for dyn Validator + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Validator")
+    }
+}
+
+/// Wraps a closure given to `Rule::closure`.
+pub(crate) struct Closure<F>(pub(crate) F);
+
+impl<F> Validator for Closure<F>
+    where F: Fn(&Context, &[&str]) -> Result<Option<Value>, ValidationError> + Send + Sync
+{
+    fn validate(&self, ctx: &Context, path: &[&str]) -> Result<Option<Value>, ValidationError> {
+        (self.0)(ctx, path)
+    }
+}
+
+/// The input available to a `Validator`.
+#[derive(Debug, Clone, Copy)]
+pub struct Context<'c> {
+    values: &'c Map,
+    pattern: &'c [&'c str],
+    concrete: &'c [String],
+}
+
+impl<'c> Context<'c> {
+    pub(crate) fn new(values: &'c Map,
+                      pattern: &'c [&'c str],
+                      concrete: &'c [String])
+                      -> Context<'c> {
+        Context {
+            values,
+            pattern,
+            concrete,
+        }
+    }
+
+    /// All of the values being validated, including changes made by earlier rules.
+    pub fn values(&self) -> &'c Map {
+        self.values
+    }
+
+    /// Returns the value at `path`, such as `["user", "email"]`, if it is present.
+    pub fn find(&self, path: &[&str]) -> Option<&'c Value> {
+        path::find(self.values, path)
+    }
+
+    /// The field name the rule was given for, which may contain wildcards, as in `items.*.sku`.
+    pub fn pattern(&self) -> &'c [&'c str] {
+        self.pattern
+    }
+
+    /// Resolves each `*` in the dotted field name `other` to the element
+    /// matched by the same `*` in the field under validation.
+    ///
+    /// For example, when validating `items.2.sku` for `items.*.sku`,
+    /// `items.*.price` resolves to `["items", "2", "price"]`.
+    pub fn resolve(&self, other: &str) -> Vec<String> {
+        path::resolve(other, self.pattern, self.concrete)
+    }
+}
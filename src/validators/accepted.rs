@@ -1,28 +1,20 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_accepted(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_accepted(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             let value = value.to_lowercase();
             if value == "yes" || value == "true" || value == "1" || value == "on" {
                 Ok(Some(Value::Boolean(true)))
             } else {
-                Err(format!("The {} must be accepted.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " ")))
+                Err(ValidationError::new(ErrorKind::Accepted, field))
             }
         }
         Some(&Value::I64(ref value)) if *value == 1 => Ok(Some(Value::Boolean(true))),
         Some(&Value::U64(ref value)) if *value == 1 => Ok(Some(Value::Boolean(true))),
         Some(&Value::Boolean(ref value)) if *value => Ok(None),
-        _ => {
-            Err(format!("The {} must be accepted.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::Accepted, field)),
     }
 }
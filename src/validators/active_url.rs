@@ -1,34 +1,45 @@
-use dns_lookup;
-
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
+use resolve::{self, Resolver};
+use url::{Host, Url};
 
-pub fn validate_active_url(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_active_url(values: &Map,
+                           field: &[&str],
+                           resolver: &dyn Resolver)
+                           -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
                 return Ok(None);
             }
-            if dns_lookup::lookup_host(value).is_ok() {
+            let active = match host(value) {
+                Some(Host::Domain(ref domain)) => resolve::has_address(resolver, domain),
+                Some(Host::Ipv4(_)) | Some(Host::Ipv6(_)) => true,
+                None => false,
+            };
+            if active {
                 Ok(None)
             } else {
-                Err(format!("The {} field must contain a valid, active domain name.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " ")))
+                Err(ValidationError::new(ErrorKind::ActiveUrl, field))
             }
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must contain a valid, active domain name.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::ActiveUrl, field)),
     }
 }
+
+/// Returns the host of the URL `value`, which may leave out the scheme,
+/// as in `example.com/path`.
+fn host(value: &str) -> Option<Host<String>> {
+    let url = if value.contains("://") {
+        Url::parse(value)
+    } else {
+        Url::parse(&format!("http://{}", value))
+    };
+    url.ok()?.host().map(|host| host.to_owned())
+}
@@ -0,0 +1,74 @@
+use error::{ErrorKind, Param, ValidationError};
+use params::{Map, Value};
+use path;
+
+pub fn validate_starts_with(values: &Map,
+                            field: &[&str],
+                            prefixes: &[&str])
+                            -> Result<Option<Value>, ValidationError> {
+    check(values, field, prefixes, ErrorKind::StartsWith, |value, prefix| {
+        value.starts_with(prefix)
+    })
+}
+
+pub fn validate_ends_with(values: &Map,
+                          field: &[&str],
+                          suffixes: &[&str])
+                          -> Result<Option<Value>, ValidationError> {
+    check(values, field, suffixes, ErrorKind::EndsWith, |value, suffix| {
+        value.ends_with(suffix)
+    })
+}
+
+pub fn validate_doesnt_start_with(values: &Map,
+                                  field: &[&str],
+                                  prefixes: &[&str])
+                                  -> Result<Option<Value>, ValidationError> {
+    check(values, field, prefixes, ErrorKind::DoesntStartWith, |value, prefix| {
+        !value.starts_with(prefix)
+    })
+}
+
+pub fn validate_doesnt_end_with(values: &Map,
+                                field: &[&str],
+                                suffixes: &[&str])
+                                -> Result<Option<Value>, ValidationError> {
+    check(values, field, suffixes, ErrorKind::DoesntEndWith, |value, suffix| {
+        !value.ends_with(suffix)
+    })
+}
+
+/// Checks the string at `field` against each of `affixes`. For the `StartsWith` and `EndsWith`
+/// rules, any affix may match, while for the `Doesnt` rules, every affix must be absent.
+fn check<F>(values: &Map,
+            field: &[&str],
+            affixes: &[&str],
+            kind: ErrorKind,
+            accept: F)
+            -> Result<Option<Value>, ValidationError>
+    where F: Fn(&str, &str) -> bool
+{
+    let error = || {
+        ValidationError::new(kind, field).with_param("values", Param::Value(affixes.join(", ")))
+    };
+    let required = matches!(kind, ErrorKind::StartsWith | ErrorKind::EndsWith);
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            let valid = if required {
+                affixes.iter().any(|affix| accept(value, affix))
+            } else {
+                affixes.iter().all(|affix| accept(value, affix))
+            };
+            if valid { Ok(None) } else { Err(error()) }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(error()),
+    }
+}
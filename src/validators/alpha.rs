@@ -1,7 +1,9 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_alpha(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_alpha(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -10,22 +12,12 @@ pub fn validate_alpha(values: &Map, field: &[&str]) -> Result<Option<Value>, Str
             if value.chars().filter(|c| !c.is_alphabetic()).count() == 0 {
                 return Ok(None);
             }
-            Err(format!("The {} field may only contain alphabetic characters.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Alpha, field))
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field may only contain alphabetic characters.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::Alpha, field)),
     }
 }
@@ -1,7 +1,9 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_alpha_dash(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_alpha_dash(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -12,11 +14,7 @@ pub fn validate_alpha_dash(values: &Map, field: &[&str]) -> Result<Option<Value>
                    .count() == 0 {
                 return Ok(None);
             }
-            Err(format!("The {} field may only contain alphanumeric characters, dashes, and underscores.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::AlphaDash, field))
         }
         Some(&Value::U64(ref value)) => Ok(Some(Value::String(format!("{}", value)))),
         Some(&Value::I64(ref value)) => Ok(Some(Value::String(format!("{}", value)))),
@@ -24,12 +22,6 @@ pub fn validate_alpha_dash(values: &Map, field: &[&str]) -> Result<Option<Value>
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field may only contain alphanumeric characters, dashes, and underscores.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::AlphaDash, field)),
     }
 }
@@ -1,7 +1,11 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_alpha_numeric(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_alpha_numeric(values: &Map,
+                              field: &[&str])
+                              -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -10,11 +14,7 @@ pub fn validate_alpha_numeric(values: &Map, field: &[&str]) -> Result<Option<Val
             if value.chars().filter(|c| !c.is_alphanumeric()).count() == 0 {
                 return Ok(None);
             }
-            Err(format!("The {} field may only contain alphanumeric characters.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::AlphaNumeric, field))
         }
         Some(&Value::U64(ref value)) => Ok(Some(Value::String(format!("{}", value)))),
         Some(&Value::I64(ref value)) if *value >= 0 => {
@@ -24,12 +24,6 @@ pub fn validate_alpha_numeric(values: &Map, field: &[&str]) -> Result<Option<Val
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field may only contain alphanumeric characters.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::AlphaNumeric, field)),
     }
 }
@@ -1,18 +1,14 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_array(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_array(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::Array(_)) => Ok(None),
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must be an array.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::Array, field)),
     }
 }
@@ -0,0 +1,23 @@
+use error::{ErrorKind, ValidationError};
+use params::{Map, Value};
+use path;
+
+pub fn validate_ascii(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            if value.is_ascii() {
+                return Ok(None);
+            }
+            Err(ValidationError::new(ErrorKind::Ascii, field))
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(ValidationError::new(ErrorKind::Ascii, field)),
+    }
+}
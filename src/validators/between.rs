@@ -1,136 +1,46 @@
+use std::cmp::Ordering;
+
+use error::{ErrorKind, Param, ValidationError};
+use measure::{Length, Measure, Number};
 use params::{Map, Value};
+use path;
 
 pub fn validate_between(values: &Map,
                         field: &[&str],
-                        min: isize,
-                        max: isize)
-                        -> Result<Option<Value>, String> {
-    assert!(max >= min);
-
-    match values.find(field) {
-        Some(&Value::String(ref value)) => {
-            if value.is_empty() {
-                // Allow empty values
-                return Ok(None);
-            }
-            assert!(min >= 0);
-            assert!(max >= 0);
-            if value.len() >= min as usize && value.len() <= max as usize {
-                Ok(None)
-            } else {
-                Err(format!("The {} field must be between {} and {} characters.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
-            }
-        }
-        Some(&Value::U64(ref value)) => {
-            if (min <= 0 || *value >= min as u64) && (max >= 0 && *value <= max as u64) {
-                Ok(None)
-            } else {
-                Err(format!("The {} field must be between {} and {}.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
-            }
-        }
-        Some(&Value::I64(ref value)) => {
-            if *value >= min as i64 && *value <= max as i64 {
-                Ok(None)
-            } else {
-                Err(format!("The {} field must be between {} and {}.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
-            }
-        }
-        Some(&Value::F64(ref value)) => {
-            if *value >= min as f64 && *value <= max as f64 {
-                Ok(None)
-            } else {
-                Err(format!("The {} field must be between {} and {}.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
-            }
-        }
-        Some(&Value::Array(ref value)) => {
-            if value.is_empty() {
-                // Allow empty values
-                return Ok(None);
-            }
-            assert!(min >= 0);
-            assert!(max >= 0);
-            if value.len() >= min as usize && value.len() <= max as usize {
-                Ok(None)
-            } else {
-                Err(format!("The {} field must have between {} and {} items.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
-            }
-        }
-        Some(&Value::Map(ref value)) => {
-            if value.is_empty() {
-                // Allow empty values
-                return Ok(None);
-            }
-            assert!(min >= 0);
-            assert!(max >= 0);
-            if value.len() >= min as usize && value.len() <= max as usize {
-                Ok(None)
-            } else {
-                Err(format!("The {} field must have between {} and {} items.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
-            }
-        }
-        Some(&Value::File(ref value)) => {
-            assert!(min >= 0);
-            assert!(max >= 0);
-            if value.size >> 10 >= min as u64 && value.size >> 10 <= max as u64 {
-                Ok(None)
-            } else {
-                Err(format!("The {} must be between {} and {} kilobytes.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
+                        min: Number,
+                        max: Number,
+                        length: Length)
+                        -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::Between, field)
+            .with_param("min", Param::Value(min.to_string()))
+            .with_param("max", Param::Value(max.to_string()))
+    };
+    match path::find(values, field) {
+        Some(value) => {
+            match Measure::of(value, length) {
+                Some(ref size) if size.is_empty() => {
+                    // Allow empty values
+                    Ok(None)
+                }
+                Some(size) => {
+                    let size = size.number();
+                    let above_min = matches!(size.compare(&min),
+                                             Some(Ordering::Greater) | Some(Ordering::Equal));
+                    let below_max = matches!(size.compare(&max),
+                                             Some(Ordering::Less) | Some(Ordering::Equal));
+                    if above_min && below_max {
+                        Ok(None)
+                    } else {
+                        Err(error())
+                    }
+                }
+                None => Err(error()),
             }
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must have a size between {} and {}.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " "),
-                        min,
-                        max))
-        }
     }
 }
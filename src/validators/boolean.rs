@@ -1,7 +1,9 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_boolean(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_boolean(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::Boolean(_)) => Ok(None),
         Some(&Value::String(ref value)) => {
             let value = value.to_lowercase();
@@ -11,11 +13,7 @@ pub fn validate_boolean(values: &Map, field: &[&str]) -> Result<Option<Value>, S
             if value == "false" || value == "0" {
                 return Ok(Some(Value::Boolean(false)));
             }
-            Err(format!("The {} field must be a boolean.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Boolean, field))
         }
         Some(&Value::U64(ref value)) => {
             if *value == 1 {
@@ -24,11 +22,7 @@ pub fn validate_boolean(values: &Map, field: &[&str]) -> Result<Option<Value>, S
             if *value == 0 {
                 return Ok(Some(Value::Boolean(false)));
             }
-            Err(format!("The {} field must be a boolean.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Boolean, field))
         }
         Some(&Value::I64(ref value)) => {
             if *value == 1 {
@@ -37,22 +31,12 @@ pub fn validate_boolean(values: &Map, field: &[&str]) -> Result<Option<Value>, S
             if *value == 0 {
                 return Ok(Some(Value::Boolean(false)));
             }
-            Err(format!("The {} field must be a boolean.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Boolean, field))
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must be a boolean.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::Boolean, field)),
     }
 }
@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+use error::{ErrorKind, Param, ValidationError};
+use measure::{Length, Measure, Number};
+use params::{Map, Value};
+use path;
+
+pub fn validate_gt(values: &Map,
+                   field: &[&str],
+                   other: &[&str],
+                   length: Length)
+                   -> Result<Option<Value>, ValidationError> {
+    compare(values, field, other, length, ErrorKind::Gt, |o| o == Ordering::Greater)
+}
+
+pub fn validate_gte(values: &Map,
+                    field: &[&str],
+                    other: &[&str],
+                    length: Length)
+                    -> Result<Option<Value>, ValidationError> {
+    compare(values, field, other, length, ErrorKind::Gte, |o| o != Ordering::Less)
+}
+
+pub fn validate_lt(values: &Map,
+                   field: &[&str],
+                   other: &[&str],
+                   length: Length)
+                   -> Result<Option<Value>, ValidationError> {
+    compare(values, field, other, length, ErrorKind::Lt, |o| o == Ordering::Less)
+}
+
+pub fn validate_lte(values: &Map,
+                    field: &[&str],
+                    other: &[&str],
+                    length: Length)
+                    -> Result<Option<Value>, ValidationError> {
+    compare(values, field, other, length, ErrorKind::Lte, |o| o != Ordering::Greater)
+}
+
+/// Compares the size of `field` with the size of the field at `other`,
+/// which must be the same kind of value.
+fn compare<F>(values: &Map,
+              field: &[&str],
+              other: &[&str],
+              length: Length,
+              kind: ErrorKind,
+              accept: F)
+              -> Result<Option<Value>, ValidationError>
+    where F: Fn(Ordering) -> bool
+{
+    let value = path::find(values, field);
+    let size = value.and_then(|value| Measure::of(value, length));
+    let target = path::find(values, other).and_then(|target| match (target, size) {
+        // Fields are validated in order of their names, so the other field may still
+        // be a string from a form, even if it will be transformed to a number later
+        (&Value::String(ref target), Some(Measure::Number(_))) => {
+            Number::parse(target).map(Measure::Number)
+        }
+        (target, _) => Measure::of(target, length),
+    });
+    let error = || {
+        let value = match target {
+            Some(target) => Param::Value(target.to_string()),
+            None => Param::Fields(vec![other.join(".")]),
+        };
+        ValidationError::new(kind, field)
+            .with_param("value", value)
+            .with_param("other", Param::Fields(vec![other.join(".")]))
+    };
+    match value {
+        Some(&Value::String(ref value)) if value.is_empty() => Ok(None),
+        Some(&Value::Array(ref value)) if value.is_empty() => Ok(None),
+        Some(&Value::Map(ref value)) if value.is_empty() => Ok(None),
+        Some(_) => {
+            match (size, target) {
+                (Some(size), Some(target)) => {
+                    match size.compare(&target) {
+                        Some(ordering) if accept(ordering) => Ok(None),
+                        _ => Err(error()),
+                    }
+                }
+                _ => Err(error()),
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::convert::TryFrom;
+
+use chrono::{DateTime, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use error::{ErrorKind, ValidationError};
+use params::{Map, Value};
+use path;
+
+/// A date or time parsed from the input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Moment {
+    Date(NaiveDate),
+    Time(NaiveTime),
+    DateTime(NaiveDateTime),
+    Zoned(DateTime<FixedOffset>),
+}
+
+impl Moment {
+    /// The ISO 8601 representation of this moment, which is `2017-03-14` for dates,
+    /// `15:09:26` for times, `2017-03-14T15:09:26` for date times without an offset,
+    /// and `2017-03-14T15:09:26Z` for date times with an offset, after conversion to UTC.
+    pub fn canonical(&self) -> String {
+        match *self {
+            Moment::Date(date) => date.format("%Y-%m-%d").to_string(),
+            Moment::Time(time) => time.format("%H:%M:%S%.f").to_string(),
+            Moment::DateTime(datetime) => datetime.format("%Y-%m-%dT%H:%M:%S%.f").to_string(),
+            Moment::Zoned(datetime) => {
+                datetime.naive_utc().format("%Y-%m-%dT%H:%M:%S%.fZ").to_string()
+            }
+        }
+    }
+
+    /// The point in time of this moment for comparisons, in UTC.
+    /// Dates are taken to be at midnight, and times to be today.
+    pub fn instant(&self) -> NaiveDateTime {
+        match *self {
+            Moment::Date(date) => date.and_time(NaiveTime::MIN),
+            Moment::Time(time) => Utc::now().date_naive().and_time(time),
+            Moment::DateTime(datetime) => datetime,
+            Moment::Zoned(datetime) => datetime.naive_utc(),
+        }
+    }
+}
+
+/// Parses an ISO 8601 or RFC 3339 date, such as `2017-03-14`, `2017-03-14T15:09:26`,
+/// `2017-03-14 15:09:26`, or `2017-03-14T15:09:26+01:00`.
+pub fn parse(value: &str) -> Option<Moment> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(Moment::Zoned(datetime));
+    }
+    for format in &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(Moment::DateTime(datetime));
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok().map(Moment::Date)
+}
+
+/// Parses `value` with a strftime-style `format`, such as `%d/%m/%Y`.
+pub fn parse_format(value: &str, format: &str) -> Option<Moment> {
+    if let Ok(datetime) = DateTime::parse_from_str(value, format) {
+        return Some(Moment::Zoned(datetime));
+    }
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(value, format) {
+        return Some(Moment::DateTime(datetime));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+        return Some(Moment::Date(date));
+    }
+    NaiveTime::parse_from_str(value, format).ok().map(Moment::Time)
+}
+
+/// Parses a date relative to the current time, such as `now`, `today`, `tomorrow`,
+/// `yesterday`, `+30 days`, `-1 week`, or `2 hours ago`.
+pub fn parse_relative(value: &str) -> Option<NaiveDateTime> {
+    let now = Utc::now().naive_utc();
+    let today = now.date().and_time(NaiveTime::MIN);
+    match value.trim().to_lowercase().as_str() {
+        "now" => return Some(now),
+        "today" => return Some(today),
+        "tomorrow" => return today.checked_add_signed(Duration::days(1)),
+        "yesterday" => return today.checked_sub_signed(Duration::days(1)),
+        _ => (),
+    }
+
+    let words = value.split_whitespace().collect::<Vec<&str>>();
+    let (amount, unit) = match words.as_slice() {
+        [amount, unit] => (amount.parse::<i64>().ok()?, *unit),
+        [amount, unit, "ago"] if !amount.starts_with(['+', '-']) => {
+            (-amount.parse::<i64>().ok()?, *unit)
+        }
+        _ => return None,
+    };
+    let unit = unit.trim_end_matches('s');
+    let months = match unit {
+        "month" => amount,
+        "year" => amount.checked_mul(12)?,
+        _ => {
+            let duration = match unit {
+                "second" => Duration::try_seconds(amount)?,
+                "minute" => Duration::try_minutes(amount)?,
+                "hour" => Duration::try_hours(amount)?,
+                "day" => Duration::try_days(amount)?,
+                "week" => Duration::try_weeks(amount)?,
+                _ => return None,
+            };
+            return now.checked_add_signed(duration);
+        }
+    };
+    let delta = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if months < 0 {
+        now.checked_sub_months(delta)
+    } else {
+        now.checked_add_months(delta)
+    }
+}
+
+pub fn validate_date(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            match parse(value) {
+                Some(moment) => Ok(Some(Value::String(moment.canonical()))),
+                None => Err(ValidationError::new(ErrorKind::Date, field)),
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(ValidationError::new(ErrorKind::Date, field)),
+    }
+}
@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+
+use chrono::NaiveDateTime;
+use error::{ErrorKind, Param, ValidationError};
+use params::{Map, Value};
+use path;
+use validators::date;
+
+pub fn validate_before(values: &Map,
+                       field: &[&str],
+                       target: &str,
+                       other: &[&str])
+                       -> Result<Option<Value>, ValidationError> {
+    compare(values, field, target, other, ErrorKind::Before, |o| o == Ordering::Less)
+}
+
+pub fn validate_before_or_equal(values: &Map,
+                                field: &[&str],
+                                target: &str,
+                                other: &[&str])
+                                -> Result<Option<Value>, ValidationError> {
+    compare(values,
+            field,
+            target,
+            other,
+            ErrorKind::BeforeOrEqual,
+            |o| o != Ordering::Greater)
+}
+
+pub fn validate_after(values: &Map,
+                      field: &[&str],
+                      target: &str,
+                      other: &[&str])
+                      -> Result<Option<Value>, ValidationError> {
+    compare(values, field, target, other, ErrorKind::After, |o| o == Ordering::Greater)
+}
+
+pub fn validate_after_or_equal(values: &Map,
+                               field: &[&str],
+                               target: &str,
+                               other: &[&str])
+                               -> Result<Option<Value>, ValidationError> {
+    compare(values,
+            field,
+            target,
+            other,
+            ErrorKind::AfterOrEqual,
+            |o| o != Ordering::Less)
+}
+
+pub fn validate_date_equals(values: &Map,
+                            field: &[&str],
+                            target: &str,
+                            other: &[&str])
+                            -> Result<Option<Value>, ValidationError> {
+    compare(values, field, target, other, ErrorKind::DateEquals, |o| o == Ordering::Equal)
+}
+
+/// Compares the date in `field` against `target`, which is either a date,
+/// a relative date such as `tomorrow`, or the name of another field, resolved as `other`.
+fn compare<F>(values: &Map,
+              field: &[&str],
+              target: &str,
+              other: &[&str],
+              kind: ErrorKind,
+              accept: F)
+              -> Result<Option<Value>, ValidationError>
+    where F: Fn(Ordering) -> bool
+{
+    let (target, param) = match date::parse(target) {
+        Some(moment) => (Some(moment.instant()), Param::Value(target.to_owned())),
+        None => {
+            match date::parse_relative(target) {
+                Some(instant) => (Some(instant), Param::Value(target.to_owned())),
+                None => (find_date(values, other), Param::Fields(vec![other.join(".")])),
+            }
+        }
+    };
+    let error = || ValidationError::new(kind, field).with_param("date", param.clone());
+
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            match (date::parse(value), target) {
+                (Some(moment), Some(target)) if accept(moment.instant().cmp(&target)) => {
+                    Ok(None)
+                }
+                _ => Err(error()),
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(error()),
+    }
+}
+
+/// Returns the date in the field at `path`, if there is a valid one.
+fn find_date(values: &Map, path: &[&str]) -> Option<NaiveDateTime> {
+    match path::find(values, path) {
+        Some(&Value::String(ref value)) => date::parse(value).map(|moment| moment.instant()),
+        _ => None,
+    }
+}
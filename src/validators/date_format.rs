@@ -0,0 +1,31 @@
+use error::{ErrorKind, Param, ValidationError};
+use params::{Map, Value};
+use path;
+use validators::date;
+
+pub fn validate_date_format(values: &Map,
+                            field: &[&str],
+                            format: &str)
+                            -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::DateFormat, field)
+            .with_param("format", Param::Value(format.to_owned()))
+    };
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            match date::parse_format(value, format) {
+                Some(moment) => Ok(Some(Value::String(moment.canonical()))),
+                None => Err(error()),
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(error()),
+    }
+}
@@ -0,0 +1,41 @@
+use error::{ErrorKind, Param, ValidationError};
+use measure::Decimal;
+use params::{Map, Value};
+use path;
+
+pub fn validate_decimal(values: &Map,
+                        field: &[&str],
+                        min: usize,
+                        max: usize)
+                        -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        let places = if min == max {
+            min.to_string()
+        } else {
+            format!("{}-{}", min, max)
+        };
+        ValidationError::new(ErrorKind::Decimal, field)
+            .with_param("places", Param::Value(places))
+            .with_param("min", Param::Value(min.to_string()))
+            .with_param("max", Param::Value(max.to_string()))
+    };
+    let decimal = match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            // Count the places as written, before `Numeric` drops any trailing zeros
+            Decimal::parse(value)
+        }
+        Some(value) => Decimal::of(value),
+        None => {
+            // Allow empty values
+            return Ok(None);
+        }
+    };
+    match decimal.map(|decimal| decimal.places()) {
+        Some(places) if min <= places && places <= max => Ok(None),
+        _ => Err(error()),
+    }
+}
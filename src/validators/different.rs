@@ -1,147 +1,73 @@
 use std::f64;
 
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_different(values: &Map,
                           field: &[&str],
                           other: &[&str])
-                          -> Result<Option<Value>, String> {
-    match values.find(field) {
+                          -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::Different, field)
+            .with_param("other", Param::Fields(vec![other.join(".")]))
+    };
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 return Ok(None);
             }
-            match values.find(other) {
+            match path::find(values, other) {
                 Some(&Value::String(ref value2)) if value != value2 => Ok(None),
-                _ => {
-                    Err(format!("The {} field must be different than the {} field.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " "),
-                                other.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                _ => Err(error()),
             }
         }
         Some(&Value::U64(ref value)) => {
-            match values.find(other) {
+            match path::find(values, other) {
                 Some(&Value::U64(ref value2)) if value != value2 => Ok(None),
-                _ => {
-                    Err(format!("The {} field must be different than the {} field.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " "),
-                                other.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                _ => Err(error()),
             }
         }
         Some(&Value::I64(ref value)) => {
-            match values.find(other) {
+            match path::find(values, other) {
                 Some(&Value::I64(ref value2)) if value != value2 => Ok(None),
-                _ => {
-                    Err(format!("The {} field must be different than the {} field.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " "),
-                                other.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                _ => Err(error()),
             }
         }
         Some(&Value::F64(ref value)) => {
-            match values.find(other) {
+            match path::find(values, other) {
                 Some(&Value::F64(ref value2)) if (value - value2).abs() >= f64::EPSILON => Ok(None),
-                _ => {
-                    Err(format!("The {} field must be different than the {} field.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " "),
-                                other.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                _ => Err(error()),
             }
         }
         Some(&Value::Boolean(ref value)) => {
-            match values.find(other) {
+            match path::find(values, other) {
                 Some(&Value::Boolean(ref value2)) if value != value2 => Ok(None),
-                _ => {
-                    Err(format!("The {} field must be different than the {} field.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " "),
-                                other.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                _ => Err(error()),
             }
         }
         Some(&Value::Array(ref value)) => {
             if value.is_empty() {
                 return Ok(None);
             }
-            match values.find(other) {
+            match path::find(values, other) {
                 Some(&Value::Array(ref value2)) if value != value2 => Ok(None),
-                _ => {
-                    Err(format!("The {} field must be different than the {} field.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " "),
-                                other.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                _ => Err(error()),
             }
         }
         Some(&Value::Map(ref value)) => {
             if value.is_empty() {
                 return Ok(None);
             }
-            match values.find(other) {
+            match path::find(values, other) {
                 Some(&Value::Map(ref value2)) if value != value2 => Ok(None),
-                _ => {
-                    Err(format!("The {} field must be different than the {} field.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " "),
-                                other.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                _ => Err(error()),
             }
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must be different than the {} field.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " "),
-                        other.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(error()),
     }
 }
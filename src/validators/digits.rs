@@ -1,10 +1,16 @@
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_digits(values: &Map,
                        field: &[&str],
                        digits: usize)
-                       -> Result<Option<Value>, String> {
-    match values.find(field) {
+                       -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::Digits, field)
+            .with_param("digits", Param::Value(digits.to_string()))
+    };
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -16,12 +22,7 @@ pub fn validate_digits(values: &Map,
                     if value.len() == digits {
                         Ok(Some(Value::U64(uvalue)))
                     } else {
-                        Err(format!("The {} field must be a number with {} digits.",
-                                    field.last()
-                                        .unwrap()
-                                        .to_lowercase()
-                                        .replace("_", " "),
-                                    digits))
+                        Err(error())
                     }
                 }
                 Err(_) => {
@@ -31,12 +32,7 @@ pub fn validate_digits(values: &Map,
                             if value[1..].len() == digits {
                                 Ok(Some(Value::I64(ivalue)))
                             } else {
-                                Err(format!("The {} field must be a number with {} digits.",
-                                            field.last()
-                                                .unwrap()
-                                                .to_lowercase()
-                                                .replace("_", " "),
-                                            digits))
+                                Err(error())
                             }
                         }
                         Err(_) => {
@@ -47,22 +43,10 @@ pub fn validate_digits(values: &Map,
                                     if value[..whole].len() == digits {
                                         Ok(Some(Value::F64(fvalue)))
                                     } else {
-                                        Err(format!("The {} field must be a number with {} digits.",
-                                                    field.last()
-                                                        .unwrap()
-                                                        .to_lowercase()
-                                                        .replace("_", " "),
-                                                    digits))
+                                        Err(error())
                                     }
                                 }
-                                Err(_) => {
-                                    Err(format!("The {} field must be a number with {} digits.",
-                                                field.last()
-                                                    .unwrap()
-                                                    .to_lowercase()
-                                                    .replace("_", " "),
-                                                digits))
-                                }
+                                Err(_) => Err(error()),
                             }
                         }
                     }
@@ -79,12 +63,7 @@ pub fn validate_digits(values: &Map,
             if count == digits {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be a number with {} digits.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            digits))
+                Err(error())
             }
         }
         Some(&Value::I64(ref value)) => {
@@ -97,12 +76,7 @@ pub fn validate_digits(values: &Map,
             if count == digits {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be a number with {} digits.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            digits))
+                Err(error())
             }
         }
         Some(&Value::F64(ref value)) => {
@@ -115,25 +89,13 @@ pub fn validate_digits(values: &Map,
             if count == digits {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be a number with {} digits.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            digits))
+                Err(error())
             }
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must be a number with {} digits.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " "),
-                        digits))
-        }
+        _ => Err(error()),
     }
 }
@@ -1,11 +1,18 @@
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_digits_between(values: &Map,
                                field: &[&str],
                                min: usize,
                                max: usize)
-                               -> Result<Option<Value>, String> {
-    match values.find(field) {
+                               -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::DigitsBetween, field)
+            .with_param("min", Param::Value(min.to_string()))
+            .with_param("max", Param::Value(max.to_string()))
+    };
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -18,13 +25,7 @@ pub fn validate_digits_between(values: &Map,
                     if len >= min && len <= max {
                         Ok(Some(Value::U64(uvalue)))
                     } else {
-                        Err(format!("The {} field must be a number with between {} and {} digits.",
-                                    field.last()
-                                        .unwrap()
-                                        .to_lowercase()
-                                        .replace("_", " "),
-                                    min,
-                                    max))
+                        Err(error())
                     }
                 }
                 Err(_) => {
@@ -35,13 +36,7 @@ pub fn validate_digits_between(values: &Map,
                             if len >= min && len <= max {
                                 Ok(Some(Value::I64(ivalue)))
                             } else {
-                                Err(format!("The {} field must be a number with between {} and {} digits.",
-                                            field.last()
-                                                .unwrap()
-                                                .to_lowercase()
-                                                .replace("_", " "),
-                                            min,
-                                            max))
+                                Err(error())
                             }
                         }
                         Err(_) => {
@@ -53,24 +48,10 @@ pub fn validate_digits_between(values: &Map,
                                     if len >= min && len <= max {
                                         Ok(Some(Value::F64(fvalue)))
                                     } else {
-                                        Err(format!("The {} field must be a number with between {} and {} digits.",
-                                                    field.last()
-                                                        .unwrap()
-                                                        .to_lowercase()
-                                                        .replace("_", " "),
-                                                    min,
-                                                    max))
+                                        Err(error())
                                     }
                                 }
-                                Err(_) => {
-                                    Err(format!("The {} field must be a number with between {} and {} digits.",
-                                                field.last()
-                                                    .unwrap()
-                                                    .to_lowercase()
-                                                    .replace("_", " "),
-                                                min,
-                                                max))
-                                }
+                                Err(_) => Err(error()),
                             }
                         }
                     }
@@ -87,13 +68,7 @@ pub fn validate_digits_between(values: &Map,
             if count >= min && count <= max {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be a number with between {} and {} digits.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
+                Err(error())
             }
         }
         Some(&Value::I64(ref value)) => {
@@ -106,13 +81,7 @@ pub fn validate_digits_between(values: &Map,
             if count >= min && count <= max {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be a number with between {} and {} digits.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
+                Err(error())
             }
         }
         Some(&Value::F64(ref value)) => {
@@ -125,27 +94,13 @@ pub fn validate_digits_between(values: &Map,
             if count >= min && count <= max {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be a number with between {} and {} digits.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            min,
-                            max))
+                Err(error())
             }
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must be a number with between {} and {} digits.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " "),
-                        min,
-                        max))
-        }
+        _ => Err(error()),
     }
 }
@@ -0,0 +1,214 @@
+use std::fs;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use error::{ErrorKind, Param, ValidationError};
+use params::{Map, Value};
+use path;
+
+/// Constraints on the size of an image, in pixels, for the `Dimensions` rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Dimensions {
+    min_width: Option<u32>,
+    max_width: Option<u32>,
+    min_height: Option<u32>,
+    max_height: Option<u32>,
+    width: Option<u32>,
+    height: Option<u32>,
+    ratio: Option<(u32, u32)>,
+}
+
+impl Dimensions {
+    /// Creates constraints which accept an image of any size.
+    pub fn new() -> Dimensions {
+        Dimensions::default()
+    }
+
+    /// Sets the minimum width of the image.
+    pub fn min_width(mut self, min_width: u32) -> Dimensions {
+        self.min_width = Some(min_width);
+        self
+    }
+
+    /// Sets the maximum width of the image.
+    pub fn max_width(mut self, max_width: u32) -> Dimensions {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the minimum height of the image.
+    pub fn min_height(mut self, min_height: u32) -> Dimensions {
+        self.min_height = Some(min_height);
+        self
+    }
+
+    /// Sets the maximum height of the image.
+    pub fn max_height(mut self, max_height: u32) -> Dimensions {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the exact width of the image.
+    pub fn width(mut self, width: u32) -> Dimensions {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the exact height of the image.
+    pub fn height(mut self, height: u32) -> Dimensions {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the exact ratio of the width to the height of the image,
+    /// such as `ratio(16, 9)` for a widescreen image.
+    pub fn ratio(mut self, width: u32, height: u32) -> Dimensions {
+        self.ratio = Some((width, height));
+        self
+    }
+
+    /// Returns the name and value of the first constraint which an image
+    /// of the given size does not meet.
+    fn violation(&self, width: u32, height: u32) -> Option<(&'static str, String)> {
+        let limits = [("width", self.width, width != self.width.unwrap_or(width)),
+                      ("height", self.height, height != self.height.unwrap_or(height)),
+                      ("min_width", self.min_width, width < self.min_width.unwrap_or(0)),
+                      ("max_width", self.max_width, width > self.max_width.unwrap_or(width)),
+                      ("min_height", self.min_height, height < self.min_height.unwrap_or(0)),
+                      ("max_height",
+                       self.max_height,
+                       height > self.max_height.unwrap_or(height))];
+        for &(name, limit, failed) in &limits {
+            if let (Some(limit), true) = (limit, failed) {
+                return Some((name, limit.to_string()));
+            }
+        }
+        match self.ratio {
+            Some((a, b)) if u64::from(width) * u64::from(b) != u64::from(height) * u64::from(a) => {
+                Some(("ratio", format!("{}/{}", a, b)))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn validate_dimensions(values: &Map,
+                           field: &[&str],
+                           dimensions: Dimensions)
+                           -> Result<Option<Value>, ValidationError> {
+    let error = || ValidationError::new(ErrorKind::Dimensions, field);
+    match path::find(values, field) {
+        Some(&Value::File(ref file)) => {
+            let (width, height) = match read_dimensions(&file.path) {
+                Ok(Some(size)) => size,
+                _ => return Err(error()),
+            };
+            match dimensions.violation(width, height) {
+                Some((constraint, limit)) => {
+                    Err(error()
+                        .with_param("constraint", Param::Value(constraint.to_owned()))
+                        .with_param(constraint, Param::Value(limit))
+                        .with_param("width", Param::Value(width.to_string()))
+                        .with_param("height", Param::Value(height.to_string())))
+                }
+                None => Ok(None),
+            }
+        }
+        Some(&Value::String(ref value)) if value.is_empty() => {
+            // Allow empty values
+            Ok(None)
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(error()),
+    }
+}
+
+/// Reads the width and height of the PNG, JPEG, GIF, WebP, or BMP image at `path`
+/// from its headers, returning `None` if it is not one of these formats.
+fn read_dimensions(path: &Path) -> io::Result<Option<(u32, u32)>> {
+    let mut reader = BufReader::new(fs::File::open(path)?);
+    let mut header = [0; 30];
+    let len = read_up_to(&mut reader, &mut header)?;
+    let header = &header[..len];
+    let be16 = |at: usize| u32::from(header[at]) << 8 | u32::from(header[at + 1]);
+    let le16 = |at: usize| u32::from(header[at]) | u32::from(header[at + 1]) << 8;
+    let le24 = |at: usize| le16(at) | u32::from(header[at + 2]) << 16;
+    let be32 = |at: usize| be16(at) << 16 | be16(at + 2);
+    let le32 = |at: usize| le16(at) | le16(at + 2) << 16;
+
+    if header.starts_with(b"\x89PNG\r\n\x1a\n") && len >= 24 {
+        Ok(Some((be32(16), be32(20))))
+    } else if header.starts_with(b"GIF8") && len >= 10 {
+        Ok(Some((le16(6), le16(8))))
+    } else if header.starts_with(b"BM") && len >= 26 {
+        if le32(14) == 12 {
+            Ok(Some((le16(18), le16(20))))
+        } else {
+            Ok(Some((le32(18), (le32(22) as i32).unsigned_abs())))
+        }
+    } else if header.starts_with(b"RIFF") && len >= 30 && &header[8..12] == b"WEBP" {
+        match &header[12..16] {
+            b"VP8 " => Ok(Some((le16(26) & 0x3fff, le16(28) & 0x3fff))),
+            b"VP8L" => {
+                let bits = le32(21);
+                Ok(Some(((bits & 0x3fff) + 1, (bits >> 14 & 0x3fff) + 1)))
+            }
+            b"VP8X" => Ok(Some((le24(24) + 1, le24(27) + 1))),
+            _ => Ok(None),
+        }
+    } else if header.starts_with(b"\xff\xd8") {
+        reader.seek(SeekFrom::Start(2))?;
+        read_jpeg_dimensions(&mut reader)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads the width and height from the first start of frame segment of a JPEG,
+/// skipping over the segments before it.
+fn read_jpeg_dimensions<R: Read + Seek>(reader: &mut R) -> io::Result<Option<(u32, u32)>> {
+    let mut byte = [0; 1];
+    loop {
+        reader.read_exact(&mut byte)?;
+        if byte[0] != 0xff {
+            return Ok(None);
+        }
+        // Markers may be preceded by any number of fill bytes
+        while byte[0] == 0xff {
+            reader.read_exact(&mut byte)?;
+        }
+        let marker = byte[0];
+        if marker == 0x01 || (0xd0..=0xd8).contains(&marker) {
+            continue;
+        }
+        let mut segment = [0; 7];
+        reader.read_exact(&mut segment[..2])?;
+        let length = u32::from(segment[0]) << 8 | u32::from(segment[1]);
+        if length < 2 {
+            return Ok(None);
+        }
+        let is_frame = matches!(marker, 0xc0..=0xc3 | 0xc5..=0xc7 | 0xc9..=0xcb | 0xcd..=0xcf);
+        if is_frame {
+            reader.read_exact(&mut segment[2..])?;
+            let height = u32::from(segment[3]) << 8 | u32::from(segment[4]);
+            let width = u32::from(segment[5]) << 8 | u32::from(segment[6]);
+            return Ok(Some((width, height)));
+        }
+        reader.seek(SeekFrom::Current(i64::from(length) - 2))?;
+    }
+}
+
+/// Fills as much of `buf` as the reader allows, returning the number of bytes read.
+fn read_up_to<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..])? {
+            0 => break,
+            read => len += read,
+        }
+    }
+    Ok(len)
+}
@@ -1,16 +1,14 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_distinct(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_distinct(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::Array(ref value)) => {
             let mut checked: Vec<Value> = Vec::with_capacity(value.len());
             for item in value {
                 if checked.contains(item) {
-                    return Err(format!("The {} field must not contain any duplicate values.",
-                                       field.last()
-                                           .unwrap()
-                                           .to_lowercase()
-                                           .replace("_", " ")));
+                    return Err(ValidationError::new(ErrorKind::Distinct, field));
                 }
                 checked.push(item.clone());
             }
@@ -1,38 +1,253 @@
 use regex::Regex;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
+use resolve::{self, RecordType, Resolver};
+use url::idna;
 
-pub fn validate_email(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    lazy_static! {
-        static ref EMAIL_REGEX: Regex = Regex::new(r".+@.+\..+").unwrap();
+/// The syntax the `Email` rule checks addresses against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmailMode {
+    /// The `addr-spec` of RFC 5322, without comments or obsolete forms, and with
+    /// the domain names that RFC 5321 allows for delivery. This accepts quoted local parts,
+    /// such as `"john doe"@example.com`, and domain literals, such as `john@[192.0.2.1]`.
+    /// This is the default.
+    #[default]
+    Rfc,
+    /// The "valid e-mail address" of the HTML standard, as checked by browsers
+    /// for `<input type="email">`, which has no quoted local parts or domain literals.
+    Html5,
+}
+
+/// Options for the `Email` rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmailOptions {
+    mode: EmailMode,
+    quoted: bool,
+    ip_literal: bool,
+    lowercase_domain: bool,
+    dns: bool,
+}
+
+impl Default for EmailOptions {
+    fn default() -> EmailOptions {
+        EmailOptions {
+            mode: EmailMode::Rfc,
+            quoted: true,
+            ip_literal: true,
+            lowercase_domain: false,
+            dns: false,
+        }
+    }
+}
+
+impl EmailOptions {
+    /// Creates options which accept any RFC 5322 address, and leave the input unchanged
+    /// unless its domain is internationalized.
+    pub fn new() -> EmailOptions {
+        EmailOptions::default()
+    }
+
+    /// Sets the syntax addresses are checked against. Defaults to `EmailMode::Rfc`.
+    pub fn mode(mut self, mode: EmailMode) -> EmailOptions {
+        self.mode = mode;
+        self
     }
 
-    match values.find(field) {
+    /// Whether to accept quoted local parts, such as `"john doe"@example.com`.
+    /// Defaults to `true`, but has no effect in `EmailMode::Html5`, which never accepts them.
+    pub fn quoted(mut self, quoted: bool) -> EmailOptions {
+        self.quoted = quoted;
+        self
+    }
+
+    /// Whether to accept an IP address in place of a domain, such as `john@[192.0.2.1]`
+    /// or `john@[IPv6:2001:db8::1]`. Defaults to `true`, but has no effect
+    /// in `EmailMode::Html5`, which never accepts them.
+    pub fn ip_literal(mut self, ip_literal: bool) -> EmailOptions {
+        self.ip_literal = ip_literal;
+        self
+    }
+
+    /// Whether to transform the domain of valid addresses to lowercase.
+    /// The local part is left unchanged, since it may be case sensitive. Defaults to `false`.
+    pub fn lowercase_domain(mut self, lowercase_domain: bool) -> EmailOptions {
+        self.lowercase_domain = lowercase_domain;
+        self
+    }
+
+    /// Whether to check that the domain accepts e-mail, by looking up its `MX` records,
+    /// or its `A` and `AAAA` records if it has none, with the `Resolver` set by
+    /// `Options::resolver`. A domain with a null `MX` record, which accepts no e-mail,
+    /// is rejected. Domain literals are not looked up. Defaults to `false`.
+    ///
+    /// By default, mail exchangers are queried from the name server in `/etc/resolv.conf`,
+    /// so where there is none, such as on Windows, set one with `SystemResolver::nameserver`.
+    pub fn dns(mut self, dns: bool) -> EmailOptions {
+        self.dns = dns;
+        self
+    }
+}
+
+pub fn validate_email(values: &Map,
+                      field: &[&str],
+                      options: EmailOptions,
+                      resolver: &dyn Resolver)
+                      -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
                 return Ok(None);
             }
-            if EMAIL_REGEX.is_match(value) {
-                // Allow valid emails
-                return Ok(None);
+            let address = match normalize(value, options) {
+                Some(address) => address,
+                None => return Err(ValidationError::new(ErrorKind::Email, field)),
+            };
+            if options.dns && !accepts_mail(resolver, &address) {
+                return Err(ValidationError::new(ErrorKind::Email, field));
+            }
+            if address != *value {
+                Ok(Some(Value::String(address)))
+            } else {
+                Ok(None)
             }
-            Err(format!("The {} field must contain a valid email address.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must contain a valid email address.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+        _ => Err(ValidationError::new(ErrorKind::Email, field)),
+    }
+}
+
+/// Checks the address `value`, returning it with an internationalized domain converted
+/// to punycode, and the domain in lowercase if the options ask for it.
+fn normalize(value: &str, options: EmailOptions) -> Option<String> {
+    lazy_static! {
+        static ref HTML5_LOCAL_REGEX: Regex =
+            Regex::new(r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+$").unwrap();
+    }
+
+    let (local, domain) = match options.mode {
+        EmailMode::Rfc => {
+            let at = local_part_len(value)?;
+            (&value[..at], value.get(at + 1..)?)
+        }
+        EmailMode::Html5 => {
+            let at = value.rfind('@')?;
+            (&value[..at], &value[at + 1..])
+        }
+    };
+    if !value[local.len()..].starts_with('@') {
+        return None;
+    }
+    let literal = domain.starts_with('[');
+    let domain = if literal {
+        if options.mode == EmailMode::Html5 || !options.ip_literal || !is_ip_literal(domain) {
+            return None;
+        }
+        domain.to_owned()
+    } else if domain.is_ascii() {
+        domain.to_owned()
+    } else {
+        idna::domain_to_ascii(domain).ok()?
+    };
+    let valid = match options.mode {
+        EmailMode::Rfc => {
+            (options.quoted || !local.starts_with('"')) &&
+            (literal || domain.split('.').all(is_label))
+        }
+        EmailMode::Html5 => {
+            HTML5_LOCAL_REGEX.is_match(local) && domain.split('.').all(is_label)
+        }
+    };
+    // RFC 5321 limits the local part to 64 octets, and the whole path to 256
+    // including the surrounding angle brackets
+    if !valid || local.len() > 64 || local.len() + domain.len() + 1 > 254 {
+        return None;
+    }
+    let domain = if options.lowercase_domain && !literal {
+        domain.to_ascii_lowercase()
+    } else {
+        domain
+    };
+    Some(format!("{}@{}", local, domain))
+}
+
+/// Whether the domain of `address` accepts e-mail. As in RFC 5321, a domain without `MX` records
+/// receives e-mail at its own address, and as in RFC 7505, a single `MX` record of `.` means
+/// that the domain accepts no e-mail.
+fn accepts_mail(resolver: &dyn Resolver, address: &str) -> bool {
+    let domain = match address.rfind('@') {
+        Some(at) => &address[at + 1..],
+        None => return false,
+    };
+    if domain.starts_with('[') {
+        return true;
+    }
+    match resolver.resolve(domain, RecordType::Mx) {
+        Ok(ref exchanges) if exchanges.is_empty() => resolve::has_address(resolver, domain),
+        Ok(ref exchanges) => {
+            !(exchanges.len() == 1 && exchanges[0].trim_end_matches('.').is_empty())
+        }
+        Err(_) => false,
+    }
+}
+
+/// Returns the length of the RFC 5322 `dot-atom` or `quoted-string` at the start of `value`.
+fn local_part_len(value: &str) -> Option<usize> {
+    if !value.starts_with('"') {
+        let len = value.find('@').unwrap_or(value.len());
+        return if is_dot_atom(&value[..len]) { Some(len) } else { None };
+    }
+    let mut escaped = false;
+    for (i, c) in value.char_indices().skip(1) {
+        if escaped {
+            // A quoted-pair may escape any printable character or whitespace
+            if !(c == ' ' || c == '\t' || c.is_ascii_graphic()) {
+                return None;
+            }
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            return Some(i + 1);
+        } else if !(c == ' ' || c == '\t' || c.is_ascii_graphic()) {
+            return None;
+        }
+    }
+    None
+}
+
+/// Whether `value` is an RFC 5322 `dot-atom`, such as `john.doe`.
+fn is_dot_atom(value: &str) -> bool {
+    value.split('.').all(|atom| {
+        !atom.is_empty() &&
+        atom.chars().all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c))
+    })
+}
+
+/// Whether `label` may be part of a domain name: letters, digits, and hyphens,
+/// which neither start nor end the label, up to 63 characters.
+fn is_label(label: &str) -> bool {
+    !label.is_empty() && label.len() <= 63 && !label.starts_with('-') && !label.ends_with('-') &&
+    label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Whether `domain` is an RFC 5321 address literal, such as `[192.0.2.1]`
+/// or `[IPv6:2001:db8::1]`.
+fn is_ip_literal(domain: &str) -> bool {
+    if !domain.ends_with(']') || domain.len() < 2 {
+        return false;
+    }
+    let address = &domain[1..domain.len() - 1];
+    match address.get(..5) {
+        Some(tag) if tag.eq_ignore_ascii_case("IPv6:") => {
+            address[5..].parse::<Ipv6Addr>().is_ok()
         }
+        _ => address.parse::<Ipv4Addr>().is_ok(),
     }
 }
@@ -0,0 +1,18 @@
+use error::{ErrorKind, ValidationError};
+use params::{Map, Value};
+use path;
+
+pub fn validate_file(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
+        Some(&Value::File(_)) => Ok(None),
+        Some(&Value::String(ref value)) if value.is_empty() => {
+            // Allow empty values
+            Ok(None)
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(ValidationError::new(ErrorKind::File, field)),
+    }
+}
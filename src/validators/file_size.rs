@@ -0,0 +1,49 @@
+use error::{ErrorKind, Param, ValidationError};
+use measure::FileSize;
+use params::{Map, Value};
+use path;
+
+pub fn validate_max_file_size(values: &Map,
+                              field: &[&str],
+                              max: FileSize)
+                              -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::MaxFileSize, field)
+            .with_param("max", Param::Value(max.to_string()))
+    };
+    check_size(values, field, error, |size| size <= max.as_bytes())
+}
+
+pub fn validate_min_file_size(values: &Map,
+                              field: &[&str],
+                              min: FileSize)
+                              -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::MinFileSize, field)
+            .with_param("min", Param::Value(min.to_string()))
+    };
+    check_size(values, field, error, |size| size >= min.as_bytes())
+}
+
+/// Checks that the value at `field` is a file whose size in bytes is accepted by `accept`.
+fn check_size<E, F>(values: &Map,
+                    field: &[&str],
+                    error: E,
+                    accept: F)
+                    -> Result<Option<Value>, ValidationError>
+    where E: Fn() -> ValidationError,
+          F: Fn(u64) -> bool
+{
+    match path::find(values, field) {
+        Some(&Value::File(ref file)) if accept(file.size) => Ok(None),
+        Some(&Value::String(ref value)) if value.is_empty() => {
+            // Allow empty values
+            Ok(None)
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(error()),
+    }
+}
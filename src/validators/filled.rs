@@ -1,35 +1,19 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_filled(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_filled(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) if value.is_empty() => {
-            Err(format!("The {} field must be filled.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Filled, field))
         }
         Some(&Value::Array(ref value)) if value.is_empty() => {
-            Err(format!("The {} field must be filled.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Filled, field))
         }
         Some(&Value::Map(ref value)) if value.is_empty() => {
-            Err(format!("The {} field must be filled.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
-        Some(&Value::Null) => {
-            Err(format!("The {} field must be filled.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Filled, field))
         }
+        Some(&Value::Null) => Err(ValidationError::new(ErrorKind::Filled, field)),
         _ => Ok(None),
     }
 }
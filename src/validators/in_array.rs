@@ -1,25 +1,21 @@
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_in_array(values: &Map,
                          field: &[&str],
                          other: &[&str])
-                         -> Result<Option<Value>, String> {
-    let options = match values.find(other) {
+                         -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::InArray, field)
+            .with_param("other", Param::Fields(vec![other.join(".")]))
+    };
+    let options = match path::find(values, other) {
         Some(&Value::Array(ref value)) => value,
-        _ => {
-            return Err(format!("The {} field must be one of the values in the {} field.",
-                               field.last()
-                                   .unwrap()
-                                   .to_lowercase()
-                                   .replace("_", " "),
-                               other.last()
-                                   .unwrap()
-                                   .to_lowercase()
-                                   .replace("_", " ")));
-        }
+        _ => return Err(error()),
     };
 
-    match values.find(field) {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) if value.is_empty() => Ok(None),
         Some(&Value::Array(ref value)) if value.is_empty() => Ok(None),
         Some(&Value::Map(ref value)) if value.is_empty() => Ok(None),
@@ -27,15 +23,7 @@ pub fn validate_in_array(values: &Map,
             if options.contains(value) {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be one of the values in the {} field.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            other.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " ")))
+                Err(error())
             }
         }
         None => Ok(None),
@@ -1,10 +1,16 @@
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_in(values: &Map,
                    field: &[&str],
                    options: &[Value])
-                   -> Result<Option<Value>, String> {
-    match values.find(field) {
+                   -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::In, field)
+            .with_param("values", Param::Values(options.to_vec()))
+    };
+    match path::find(values, field) {
         Some(&Value::String(ref value)) if value.is_empty() => Ok(None),
         Some(&Value::Array(ref value)) if value.is_empty() => Ok(None),
         Some(&Value::Map(ref value)) if value.is_empty() => Ok(None),
@@ -12,12 +18,7 @@ pub fn validate_in(values: &Map,
             if options.contains(value) {
                 Ok(None)
             } else {
-                Err(format!("The {} field must be among the options: {:?}.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            options))
+                Err(error())
             }
         }
         None => Ok(None),
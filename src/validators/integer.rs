@@ -1,7 +1,9 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_integer(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_integer(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -14,13 +16,7 @@ pub fn validate_integer(values: &Map, field: &[&str]) -> Result<Option<Value>, S
                     let negative = value.parse::<i64>();
                     match negative {
                         Ok(ivalue) => Ok(Some(Value::I64(ivalue))),
-                        Err(_) => {
-                            Err(format!("The {} field must be an integer.",
-                                        field.last()
-                                            .unwrap()
-                                            .to_lowercase()
-                                            .replace("_", " ")))
-                        }
+                        Err(_) => Err(ValidationError::new(ErrorKind::Integer, field)),
                     }
                 }
             }
@@ -31,12 +27,6 @@ pub fn validate_integer(values: &Map, field: &[&str]) -> Result<Option<Value>, S
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must be an integer.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::Integer, field)),
     }
 }
@@ -1,10 +1,12 @@
 use serde_json;
 
 
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_json(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_json(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -13,22 +15,12 @@ pub fn validate_json(values: &Map, field: &[&str]) -> Result<Option<Value>, Stri
             if serde_json::from_str::<serde_json::Value>(value).is_ok() {
                 return Ok(None);
             }
-            Err(format!("The {} field must contain a valid JSON string.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Json, field))
         }
         None => {
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must contain a valid JSON string.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::Json, field)),
     }
 }
@@ -0,0 +1,126 @@
+use error::{ErrorKind, ValidationError};
+use params::{Map, Value};
+use path;
+
+/// A notation for MAC addresses, in which the `MacAddress` rule can write valid addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacNotation {
+    /// Pairs of digits separated by colons, as in `00:1a:2b:3c:4d:5e`.
+    Colon,
+    /// Pairs of digits separated by hyphens, as in `00-1a-2b-3c-4d-5e`.
+    Hyphen,
+    /// Groups of four digits separated by dots, as used by Cisco, as in `001a.2b3c.4d5e`.
+    Dot,
+    /// Digits without separators, as in `001a2b3c4d5e`.
+    Bare,
+}
+
+/// Options for the `MacAddress` rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MacAddressOptions {
+    unicast: bool,
+    universal: bool,
+    notation: Option<MacNotation>,
+}
+
+impl MacAddressOptions {
+    /// Creates options which accept any EUI-48 or EUI-64 address in any notation,
+    /// and leave the input unchanged.
+    pub fn new() -> MacAddressOptions {
+        MacAddressOptions::default()
+    }
+
+    /// Whether to reject multicast addresses, which have the lowest bit
+    /// of the first octet set. Defaults to `false`.
+    pub fn unicast(mut self, unicast: bool) -> MacAddressOptions {
+        self.unicast = unicast;
+        self
+    }
+
+    /// Whether to reject locally administered addresses, which have the second lowest bit
+    /// of the first octet set. Defaults to `false`.
+    pub fn universal(mut self, universal: bool) -> MacAddressOptions {
+        self.universal = universal;
+        self
+    }
+
+    /// Sets the notation that valid addresses are transformed to, in lowercase.
+    /// By default, the input is left unchanged.
+    pub fn notation(mut self, notation: MacNotation) -> MacAddressOptions {
+        self.notation = Some(notation);
+        self
+    }
+}
+
+pub fn validate_mac_address(values: &Map,
+                            field: &[&str],
+                            options: MacAddressOptions)
+                            -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            let octets = match parse(value) {
+                Some(octets) => octets,
+                None => return Err(ValidationError::new(ErrorKind::MacAddress, field)),
+            };
+            if (options.unicast && octets[0] & 0x01 != 0) ||
+               (options.universal && octets[0] & 0x02 != 0) {
+                return Err(ValidationError::new(ErrorKind::MacAddress, field));
+            }
+            match options.notation {
+                Some(notation) => {
+                    let formatted = format(&octets, notation);
+                    if formatted != *value {
+                        Ok(Some(Value::String(formatted)))
+                    } else {
+                        Ok(None)
+                    }
+                }
+                None => Ok(None),
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(ValidationError::new(ErrorKind::MacAddress, field)),
+    }
+}
+
+/// Parses an EUI-48 or EUI-64 address in any of the `MacNotation`s, returning its octets.
+fn parse(value: &str) -> Option<Vec<u8>> {
+    let (digits, group) = if value.contains(':') {
+        (value.split(':').collect::<Vec<_>>(), 2)
+    } else if value.contains('-') {
+        (value.split('-').collect(), 2)
+    } else if value.contains('.') {
+        (value.split('.').collect(), 4)
+    } else {
+        (vec![value], value.len())
+    };
+    if digits.iter().any(|digits| digits.len() != group) {
+        return None;
+    }
+    let digits = digits.concat();
+    if !(digits.len() == 12 || digits.len() == 16) ||
+       !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).ok())
+        .collect()
+}
+
+fn format(octets: &[u8], notation: MacNotation) -> String {
+    let pairs = octets.iter().map(|octet| format!("{:02x}", octet)).collect::<Vec<_>>();
+    match notation {
+        MacNotation::Colon => pairs.join(":"),
+        MacNotation::Hyphen => pairs.join("-"),
+        MacNotation::Dot => pairs.chunks(2).map(|pair| pair.concat()).collect::<Vec<_>>().join("."),
+        MacNotation::Bare => pairs.concat(),
+    }
+}
@@ -0,0 +1,102 @@
+use std::path::Path;
+
+use error::{ErrorKind, Param, ValidationError};
+use params::{File, Map, Value};
+use path;
+use sniff;
+
+/// The types accepted by the `Image` rule, which leaves out SVG, as it may contain scripts.
+const IMAGE_TYPES: &[&str] = &["image/jpeg", "image/png", "image/gif", "image/bmp", "image/webp"];
+
+pub fn validate_mimes(values: &Map,
+                      field: &[&str],
+                      extensions: &[&str])
+                      -> Result<Option<Value>, ValidationError> {
+    let error = |kind| {
+        ValidationError::new(kind, field)
+            .with_param("values", Param::Value(extensions.join(", ")))
+    };
+    check_file(values, field, || error(ErrorKind::Mimes), |file| {
+        let name = file.filename.as_ref().map(Path::new).unwrap_or(&file.path);
+        let extension = match name.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) => extension,
+            None => return Err(error(ErrorKind::Mimes)),
+        };
+        if !extensions.iter().any(|allowed| allowed.eq_ignore_ascii_case(extension)) {
+            return Err(error(ErrorKind::Mimes));
+        }
+        let sniffed = sniff::sniff(&file.path).map_err(|_| error(ErrorKind::Mimes))?;
+        match sniff::from_extension(extension) {
+            Some(mime) if !sniff::agrees(mime, sniffed) => Err(error(ErrorKind::MimeMismatch)),
+            _ => Ok(None),
+        }
+    })
+}
+
+pub fn validate_mime_types(values: &Map,
+                           field: &[&str],
+                           types: &[&str])
+                           -> Result<Option<Value>, ValidationError> {
+    check_types(values, field, types, ErrorKind::MimeTypes)
+}
+
+pub fn validate_image(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    check_types(values, field, IMAGE_TYPES, ErrorKind::Image)
+}
+
+/// Checks that the declared type of the file at `field` is one of `types`,
+/// and that its contents agree with the declared type.
+fn check_types(values: &Map,
+               field: &[&str],
+               types: &[&str],
+               kind: ErrorKind)
+               -> Result<Option<Value>, ValidationError> {
+    let error = |kind| {
+        ValidationError::new(kind, field).with_param("values", Param::Value(types.join(", ")))
+    };
+    check_file(values, field, || error(kind), |file| {
+        let declared = sniff::essence(&file.content_type);
+        if !types.iter().any(|allowed| type_matches(allowed, &declared)) {
+            return Err(error(kind));
+        }
+        let sniffed = sniff::sniff(&file.path).map_err(|_| error(kind))?;
+        if sniff::agrees(&declared, sniffed) {
+            Ok(None)
+        } else {
+            Err(error(ErrorKind::MimeMismatch))
+        }
+    })
+}
+
+/// Whether the MIME type `mime` matches `allowed`, which may end in a wildcard like `image/*`.
+fn type_matches(allowed: &str, mime: &str) -> bool {
+    if allowed.ends_with("/*") {
+        let prefix = &allowed[..allowed.len() - 1];
+        mime.get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    } else {
+        allowed.eq_ignore_ascii_case(mime)
+    }
+}
+
+/// Runs `check` on the file at `field`, failing with `error` if the value is not a file.
+fn check_file<E, F>(values: &Map,
+                    field: &[&str],
+                    error: E,
+                    check: F)
+                    -> Result<Option<Value>, ValidationError>
+    where E: FnOnce() -> ValidationError,
+          F: FnOnce(&File) -> Result<Option<Value>, ValidationError>
+{
+    match path::find(values, field) {
+        Some(&Value::File(ref file)) => check(file),
+        Some(&Value::String(ref value)) if value.is_empty() => {
+            // Allow empty values
+            Ok(None)
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(error()),
+    }
+}
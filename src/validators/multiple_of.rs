@@ -0,0 +1,33 @@
+use error::{ErrorKind, Param, ValidationError};
+use measure::{Decimal, Number};
+use params::{Map, Value};
+use path;
+
+pub fn validate_multiple_of(values: &Map,
+                            field: &[&str],
+                            step: Number)
+                            -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::MultipleOf, field)
+            .with_param("value", Param::Value(step.to_string()))
+    };
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) if value.is_empty() => {
+            // Allow empty values
+            Ok(None)
+        }
+        Some(value) => {
+            let multiple = Decimal::of(value)
+                .and_then(|value| value.is_multiple_of(&Decimal::of_number(step)?));
+            if multiple == Some(true) {
+                Ok(None)
+            } else {
+                Err(error())
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+    }
+}
@@ -1,21 +1,22 @@
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_not_in(values: &Map,
                        field: &[&str],
                        options: &[Value])
-                       -> Result<Option<Value>, String> {
-    match values.find(field) {
+                       -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::NotIn, field)
+            .with_param("values", Param::Values(options.to_vec()))
+    };
+    match path::find(values, field) {
         Some(&Value::String(ref value)) if value.is_empty() => Ok(None),
         Some(&Value::Array(ref value)) if value.is_empty() => Ok(None),
         Some(&Value::Map(ref value)) if value.is_empty() => Ok(None),
         Some(value) => {
             if options.contains(value) {
-                Err(format!("The {} field must not be among the options: {:?}.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " "),
-                            options))
+                Err(error())
             } else {
                 Ok(None)
             }
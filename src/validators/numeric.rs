@@ -1,7 +1,9 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_numeric(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_numeric(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) => {
             if value.is_empty() {
                 // Allow empty values
@@ -18,13 +20,7 @@ pub fn validate_numeric(values: &Map, field: &[&str]) -> Result<Option<Value>, S
                             let float = value.parse::<f64>();
                             match float {
                                 Ok(fvalue) => Ok(Some(Value::F64(fvalue))),
-                                Err(_) => {
-                                    Err(format!("The {} field must be numeric.",
-                                                field.last()
-                                                    .unwrap()
-                                                    .to_lowercase()
-                                                    .replace("_", " ")))
-                                }
+                                Err(_) => Err(ValidationError::new(ErrorKind::Numeric, field)),
                             }
                         }
                     }
@@ -38,12 +34,6 @@ pub fn validate_numeric(values: &Map, field: &[&str]) -> Result<Option<Value>, S
             // Allow empty values
             Ok(None)
         }
-        _ => {
-            Err(format!("The {} field must be numeric.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        _ => Err(ValidationError::new(ErrorKind::Numeric, field)),
     }
 }
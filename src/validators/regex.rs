@@ -1,14 +1,22 @@
 use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_regex(values: &Map,
                       field: &[&str],
                       pattern: &str)
-                      -> Result<Option<Value>, String> {
-    let pattern = Regex::new(pattern).expect("Invalid pattern passed into Regex validator");
+                      -> Result<Option<Value>, ValidationError> {
+    let pattern = compile(pattern);
+    let error = || {
+        ValidationError::new(ErrorKind::Regex, field)
+            .with_param("pattern", Param::Value(pattern.as_str().to_owned()))
+    };
 
-    let value = match values.find(field) {
+    let value = match path::find(values, field) {
         Some(&Value::String(ref value)) => value.clone(),
         Some(&Value::U64(ref value)) => format!("{}", *value),
         Some(&Value::I64(ref value)) => format!("{}", *value),
@@ -18,24 +26,33 @@ pub fn validate_regex(values: &Map,
             // Allow empty values
             return Ok(None);
         }
-        _ => {
-            return Err(format!("The {} field must match the pattern \"{}\".",
-                               field.last()
-                                   .unwrap()
-                                   .to_lowercase()
-                                   .replace("_", " "),
-                               pattern));
-        }
+        _ => return Err(error()),
     };
 
     if pattern.is_match(&value) {
         Ok(Some(Value::String(value)))
     } else {
-        Err(format!("The {} field must match the pattern \"{}\".",
-                    field.last()
-                        .unwrap()
-                        .to_lowercase()
-                        .replace("_", " "),
-                    pattern))
+        Err(error())
+    }
+}
+
+/// The number of compiled patterns kept, after which the cache is emptied.
+const CACHE_CAPACITY: usize = 256;
+
+/// Returns `pattern` compiled, reusing the result of an earlier call for the same pattern.
+fn compile(pattern: &str) -> Regex {
+    lazy_static! {
+        static ref CACHE: Mutex<HashMap<String, Regex>> = Mutex::new(HashMap::new());
+    }
+
+    let mut cache = CACHE.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(regex) = cache.get(pattern) {
+        return regex.clone();
+    }
+    let regex = Regex::new(pattern).expect("Invalid pattern passed into Regex validator");
+    if cache.len() >= CACHE_CAPACITY {
+        cache.clear();
     }
+    cache.insert(pattern.to_owned(), regex.clone());
+    regex
 }
@@ -1,36 +1,20 @@
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
-pub fn validate_required(values: &Map, field: &[&str]) -> Result<Option<Value>, String> {
-    match values.find(field) {
+pub fn validate_required(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
         Some(&Value::String(ref value)) if value.is_empty() => {
-            Err(format!("The {} field is required.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Required, field))
         }
         Some(&Value::Array(ref value)) if value.is_empty() => {
-            Err(format!("The {} field is required.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Required, field))
         }
         Some(&Value::Map(ref value)) if value.is_empty() => {
-            Err(format!("The {} field is required.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
+            Err(ValidationError::new(ErrorKind::Required, field))
         }
         Some(&Value::Null) |
-        None => {
-            Err(format!("The {} field is required.",
-                        field.last()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace("_", " ")))
-        }
+        None => Err(ValidationError::new(ErrorKind::Required, field)),
         _ => Ok(None),
     }
 }
@@ -1,42 +1,25 @@
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_required_if(values: &Map,
                             field: &[&str],
                             other: &[&str],
-                            condition: &Value)
-                            -> Result<Option<Value>, String> {
-    match values.find(other) {
-        Some(value) if *value == *condition => {
-            match values.find(field) {
-                Some(&Value::String(ref value)) if value.is_empty() => {
-                    Err(format!("The {} field is required.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
-                Some(&Value::Array(ref value)) if value.is_empty() => {
-                    Err(format!("The {} field is required.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
-                Some(&Value::Map(ref value)) if value.is_empty() => {
-                    Err(format!("The {} field is required.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                            conditions: &[Value])
+                            -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::RequiredIf, field)
+            .with_param("other", Param::Fields(vec![other.join(".")]))
+            .with_param("value", Param::Values(conditions.to_vec()))
+    };
+    match path::find(values, other) {
+        Some(value) if conditions.contains(value) => {
+            match path::find(values, field) {
+                Some(&Value::String(ref value)) if value.is_empty() => Err(error()),
+                Some(&Value::Array(ref value)) if value.is_empty() => Err(error()),
+                Some(&Value::Map(ref value)) if value.is_empty() => Err(error()),
                 Some(&Value::Null) |
-                None => {
-                    Err(format!("The {} field is required.",
-                                field.last()
-                                    .unwrap()
-                                    .to_lowercase()
-                                    .replace("_", " ")))
-                }
+                None => Err(error()),
                 _ => Ok(None),
             }
         }
@@ -1,12 +1,19 @@
+use error::{ErrorKind, Param, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_required_with_all(values: &Map,
                                   field: &[&str],
                                   others: &[Vec<&str>])
-                                  -> Result<Option<Value>, String> {
+                                  -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        ValidationError::new(ErrorKind::RequiredWithAll, field)
+            .with_param("values",
+                        Param::Fields(others.iter().map(|other| other.join(".")).collect()))
+    };
     let mut required = true;
     for other in others {
-        let current = match values.find(other) {
+        let current = match path::find(values, other) {
             None |
             Some(&Value::Null) => false,
             Some(&Value::String(ref value)) if value.is_empty() => false,
@@ -21,36 +28,12 @@ pub fn validate_required_with_all(values: &Map,
     }
 
     if required {
-        match values.find(field) {
-            Some(&Value::String(ref value)) if value.is_empty() => {
-                Err(format!("The {} field is required.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " ")))
-            }
-            Some(&Value::Array(ref value)) if value.is_empty() => {
-                Err(format!("The {} field is required.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " ")))
-            }
-            Some(&Value::Map(ref value)) if value.is_empty() => {
-                Err(format!("The {} field is required.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " ")))
-            }
+        match path::find(values, field) {
+            Some(&Value::String(ref value)) if value.is_empty() => Err(error()),
+            Some(&Value::Array(ref value)) if value.is_empty() => Err(error()),
+            Some(&Value::Map(ref value)) if value.is_empty() => Err(error()),
             Some(&Value::Null) |
-            None => {
-                Err(format!("The {} field is required.",
-                            field.last()
-                                .unwrap()
-                                .to_lowercase()
-                                .replace("_", " ")))
-            }
+            None => Err(error()),
             _ => Ok(None),
         }
     } else {
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use error::{ErrorKind, ValidationError};
+use params::{Map, Value};
+use path;
+
+lazy_static! {
+    /// Maps each IANA time zone name, including links such as `US/Eastern`,
+    /// to the name of the zone it refers to.
+    static ref TIMEZONES: BTreeMap<&'static str, &'static str> =
+        include_str!("../timezones.txt")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let mut names = line.split(' ');
+                let name = names.next().unwrap();
+                (name, names.next().unwrap_or(name))
+            })
+            .collect();
+}
+
+/// Options for the `Timezone` rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimezoneOptions {
+    canonicalize: bool,
+    offsets: bool,
+}
+
+impl TimezoneOptions {
+    /// Creates options which accept any IANA time zone name, and leave the input unchanged.
+    pub fn new() -> TimezoneOptions {
+        TimezoneOptions::default()
+    }
+
+    /// Whether to replace links with the name of the zone they refer to,
+    /// such as `US/Eastern` with `America/New_York`, and to write fixed offsets
+    /// in the form `+05:30`. Defaults to `false`.
+    pub fn canonicalize(mut self, canonicalize: bool) -> TimezoneOptions {
+        self.canonicalize = canonicalize;
+        self
+    }
+
+    /// Whether to accept fixed offsets from UTC, such as `+05:30` or `-0800`,
+    /// from `-12:00` to `+14:00`. Defaults to `false`.
+    pub fn offsets(mut self, offsets: bool) -> TimezoneOptions {
+        self.offsets = offsets;
+        self
+    }
+}
+
+pub fn validate_timezone(values: &Map,
+                         field: &[&str],
+                         options: TimezoneOptions)
+                         -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            let canonical = match TIMEZONES.get(value.as_str()) {
+                Some(zone) => zone.to_string(),
+                None if options.offsets => {
+                    match parse_offset(value) {
+                        Some(offset) => offset,
+                        None => return Err(ValidationError::new(ErrorKind::Timezone, field)),
+                    }
+                }
+                None => return Err(ValidationError::new(ErrorKind::Timezone, field)),
+            };
+            if options.canonicalize && canonical != *value {
+                Ok(Some(Value::String(canonical)))
+            } else {
+                Ok(None)
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(ValidationError::new(ErrorKind::Timezone, field)),
+    }
+}
+
+/// Parses a fixed offset from UTC, such as `+05:30` or `-0800`,
+/// returning it in the form `+05:30`.
+fn parse_offset(value: &str) -> Option<String> {
+    if !value.is_ascii() {
+        return None;
+    }
+    let sign = match value.chars().next() {
+        Some(sign @ '+') | Some(sign @ '-') => sign,
+        _ => return None,
+    };
+    let digits = match value[1..].len() {
+        5 if value[3..4] == *":" => format!("{}{}", &value[1..3], &value[4..]),
+        4 => value[1..].to_owned(),
+        _ => return None,
+    };
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let hours = digits[..2].parse::<u8>().ok()?;
+    let minutes = digits[2..].parse::<u8>().ok()?;
+    // Offsets in use range from -12:00 to +14:00
+    let limit = if sign == '+' { 14 * 60 } else { 12 * 60 };
+    if minutes > 59 || u16::from(hours) * 60 + u16::from(minutes) > limit {
+        return None;
+    }
+    Some(format!("{}{:02}:{:02}", sign, hours, minutes))
+}
@@ -0,0 +1,35 @@
+use error::{ErrorKind, ValidationError};
+use params::{Map, Value};
+use path;
+
+/// The characters of Crockford's base32, in the order of their values.
+const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+pub fn validate_ulid(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            let canonical = value.to_ascii_uppercase();
+            // A ULID is 128 bits, so the first character of its 130 bits of base32
+            // holds at most 3 bits, which keeps the 48-bit timestamp in range
+            let valid = canonical.len() == 26 &&
+                        canonical.chars().all(|c| CROCKFORD.contains(c)) &&
+                        canonical.as_bytes()[0] <= b'7';
+            if !valid {
+                Err(ValidationError::new(ErrorKind::Ulid, field))
+            } else if canonical != *value {
+                Ok(Some(Value::String(canonical)))
+            } else {
+                Ok(None)
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(ValidationError::new(ErrorKind::Ulid, field)),
+    }
+}
@@ -1,14 +1,16 @@
 use postgres::Connection;
 use postgres::types::ToSql;
 
+use error::{ErrorKind, ValidationError};
 use params::{Map, Value};
+use path;
 
 pub fn validate_unique(conn: &Connection,
                        values: &Map,
                        field: &[&str],
                        table: &str,
                        column: Option<&str>)
-                       -> Result<Option<Value>, String> {
+                       -> Result<Option<Value>, ValidationError> {
     let column = if let Some(c) = column {
         c
     } else {
@@ -18,7 +20,7 @@ pub fn validate_unique(conn: &Connection,
                         column,
                         table,
                         column);
-    let result = match values.find(field) {
+    let result = match path::find(values, field) {
         Some(&Value::String(ref value)) => conn.query(&query, vec![value as &ToSql].as_slice()),
         Some(&Value::U64(ref value)) => {
             conn.query(&query, vec![&(*value as i64) as &ToSql].as_slice())
@@ -27,23 +29,13 @@ pub fn validate_unique(conn: &Connection,
         Some(&Value::F64(ref value)) => conn.query(&query, vec![value as &ToSql].as_slice()),
         Some(&Value::Boolean(ref value)) => conn.query(&query, vec![value as &ToSql].as_slice()),
         None => conn.query(&query, vec![&"" as &ToSql].as_slice()),
-        _ => {
-            return Err(format!("The {} field must be unique.",
-                               field.last()
-                                   .unwrap()
-                                   .to_lowercase()
-                                   .replace("_", " ")));
-        }
+        _ => return Err(ValidationError::new(ErrorKind::Unique, field)),
     };
 
     let count: i64 = result.unwrap().get(0).get(0);
     if count == 0 {
         Ok(None)
     } else {
-        Err(format!("The {} field must be unique.",
-                    field.last()
-                        .unwrap()
-                        .to_lowercase()
-                        .replace("_", " ")))
+        Err(ValidationError::new(ErrorKind::Unique, field))
     }
 }
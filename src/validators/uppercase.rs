@@ -0,0 +1,23 @@
+use error::{ErrorKind, ValidationError};
+use params::{Map, Value};
+use path;
+
+pub fn validate_uppercase(values: &Map, field: &[&str]) -> Result<Option<Value>, ValidationError> {
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            if value.to_uppercase() == *value {
+                return Ok(None);
+            }
+            Err(ValidationError::new(ErrorKind::Uppercase, field))
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(ValidationError::new(ErrorKind::Uppercase, field)),
+    }
+}
@@ -0,0 +1,123 @@
+use error::{ErrorKind, Param, ValidationError};
+use params::{Map, Value};
+use path;
+
+/// Options for the `Uuid` rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UuidOptions {
+    versions: u16,
+    canonical: bool,
+}
+
+impl UuidOptions {
+    /// Creates options which accept a UUID of any version in any of the common forms.
+    pub fn new() -> UuidOptions {
+        UuidOptions::default()
+    }
+
+    /// Adds a version of UUID to accept, from 1 to 8, such as `4` for random UUIDs.
+    /// By default, any version is accepted, as are the nil and max UUIDs.
+    ///
+    /// Once a version is given, only UUIDs of the given versions with the RFC 9562 variant
+    /// are accepted.
+    pub fn version(mut self, version: u8) -> UuidOptions {
+        if version < 16 {
+            self.versions |= 1 << version;
+        }
+        self
+    }
+
+    /// Whether to accept only the canonical form, which is lowercase and hyphenated,
+    /// as in `67e55044-10b1-426f-9247-bb680e5fe0c8`. Defaults to `false`.
+    ///
+    /// Otherwise, uppercase, unhyphenated, braced, and `urn:uuid:` forms are accepted
+    /// and transformed to the canonical form.
+    pub fn canonical(mut self, canonical: bool) -> UuidOptions {
+        self.canonical = canonical;
+        self
+    }
+}
+
+pub fn validate_uuid(values: &Map,
+                     field: &[&str],
+                     options: UuidOptions)
+                     -> Result<Option<Value>, ValidationError> {
+    let error = || {
+        let versions = (1..16)
+            .filter(|version| options.versions & 1 << version != 0)
+            .map(|version| version.to_string())
+            .collect::<Vec<_>>();
+        ValidationError::new(ErrorKind::Uuid, field)
+            .with_param("versions", Param::Value(versions.join(", ")))
+    };
+    match path::find(values, field) {
+        Some(&Value::String(ref value)) => {
+            if value.is_empty() {
+                // Allow empty values
+                return Ok(None);
+            }
+            let canonical = match canonicalize(value) {
+                Some(ref canonical) if options.canonical && canonical != value => {
+                    return Err(error())
+                }
+                Some(canonical) => canonical,
+                None => return Err(error()),
+            };
+            if options.versions != 0 {
+                let bytes = canonical.as_bytes();
+                let version = (bytes[14] as char).to_digit(16).unwrap();
+                let variant = (bytes[19] as char).to_digit(16).unwrap();
+                if options.versions & 1 << version == 0 || variant & 0b1100 != 0b1000 {
+                    return Err(error());
+                }
+            }
+            if canonical != *value {
+                Ok(Some(Value::String(canonical)))
+            } else {
+                Ok(None)
+            }
+        }
+        None => {
+            // Allow empty values
+            Ok(None)
+        }
+        _ => Err(error()),
+    }
+}
+
+/// Returns the lowercase, hyphenated form of the UUID `value`, which may be uppercase,
+/// unhyphenated, surrounded by braces, or prefixed with `urn:uuid:`.
+fn canonicalize(value: &str) -> Option<String> {
+    let prefix = "urn:uuid:";
+    let value = if value.get(..prefix.len()).unwrap_or("").eq_ignore_ascii_case(prefix) {
+        &value[prefix.len()..]
+    } else if value.starts_with('{') && value.ends_with('}') && value.len() > 1 {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    };
+    let digits: String = match value.len() {
+        32 => value.to_owned(),
+        36 => {
+            let hyphens = value.char_indices()
+                .filter(|&(_, c)| c == '-')
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            if hyphens != [8, 13, 18, 23] {
+                return None;
+            }
+            value.chars().filter(|&c| c != '-').collect()
+        }
+        _ => return None,
+    };
+    if digits.len() != 32 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits = digits.to_ascii_lowercase();
+    Some(format!("{}-{}-{}-{}-{}",
+                 &digits[..8],
+                 &digits[8..12],
+                 &digits[12..16],
+                 &digits[16..20],
+                 &digits[20..]))
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -121,7 +124,7 @@ fn test_accepted_0_u64_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -136,7 +139,7 @@ fn test_accepted_0_i64_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -151,7 +154,7 @@ fn test_accepted_1_f64_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -166,7 +169,7 @@ fn test_accepted_false_boolean_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -181,7 +184,7 @@ fn test_accepted_false_string_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -196,7 +199,7 @@ fn test_accepted_empty_string_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -210,7 +213,7 @@ fn test_accepted_blank_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -225,7 +228,7 @@ fn test_accepted_null_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("accepted").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("accepted").unwrap()),
                vec!["The accepted must be accepted.".to_owned()]);
 }
 
@@ -0,0 +1,139 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{ErrorKind, Param, Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: Value, rule: Rule) -> Result<(), Vec<String>> {
+    let mut params = Map::new();
+    params.assign("code", value).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("code", vec![rule]);
+
+    validate(&rules, params)
+        .map(|_| ())
+        .map_err(|errors| errors["code"].iter().map(|error| error.to_string()).collect())
+}
+
+fn string(value: &str) -> Value {
+    Value::String(value.to_owned())
+}
+
+#[test]
+fn test_starts_with_valid() {
+    assert_eq!(check(string("INV-2017"), Rule::StartsWith(vec!["ORD-", "INV-"])), Ok(()));
+}
+
+#[test]
+fn test_starts_with_invalid() {
+    assert_eq!(check(string("ACC-2017"), Rule::StartsWith(vec!["ORD-", "INV-"])),
+               Err(vec!["The code field must start with one of the following: ORD-, INV-."
+                            .to_owned()]));
+}
+
+#[test]
+fn test_starts_with_is_case_sensitive() {
+    assert!(check(string("inv-2017"), Rule::StartsWith(vec!["INV-"])).is_err());
+}
+
+#[test]
+fn test_ends_with_valid() {
+    assert_eq!(check(string("report.csv"), Rule::EndsWith(vec![".csv", ".tsv"])), Ok(()));
+}
+
+#[test]
+fn test_ends_with_invalid() {
+    assert_eq!(check(string("report.xls"), Rule::EndsWith(vec![".csv", ".tsv"])),
+               Err(vec!["The code field must end with one of the following: .csv, .tsv."
+                            .to_owned()]));
+}
+
+#[test]
+fn test_doesnt_start_with_valid() {
+    assert_eq!(check(string("public"), Rule::DoesntStartWith(vec!["_", "."])), Ok(()));
+}
+
+#[test]
+fn test_doesnt_start_with_invalid() {
+    assert_eq!(check(string(".hidden"), Rule::DoesntStartWith(vec!["_", "."])),
+               Err(vec!["The code field must not start with any of the following: _, .."
+                            .to_owned()]));
+}
+
+#[test]
+fn test_doesnt_end_with_valid() {
+    assert_eq!(check(string("report.csv"), Rule::DoesntEndWith(vec![".exe", ".bat"])), Ok(()));
+}
+
+#[test]
+fn test_doesnt_end_with_invalid() {
+    assert!(check(string("setup.exe"), Rule::DoesntEndWith(vec![".exe", ".bat"])).is_err());
+}
+
+#[test]
+fn test_starts_with_unicode() {
+    assert!(check(string("été"), Rule::StartsWith(vec!["é"])).is_ok());
+    assert!(check(string("été"), Rule::EndsWith(vec!["é"])).is_ok());
+    assert!(check(string("ete"), Rule::StartsWith(vec!["é"])).is_err());
+}
+
+#[test]
+fn test_starts_with_invalid_type() {
+    assert!(check(Value::U64(2017), Rule::StartsWith(vec!["2"])).is_err());
+    assert!(check(Value::U64(2017), Rule::DoesntStartWith(vec!["3"])).is_err());
+}
+
+#[test]
+fn test_starts_with_valid_empty() {
+    assert!(check(string(""), Rule::StartsWith(vec!["INV-"])).is_ok());
+    assert!(check(string(""), Rule::EndsWith(vec![".csv"])).is_ok());
+}
+
+#[test]
+fn test_starts_with_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("code", vec![Rule::StartsWith(vec!["INV-"]), Rule::DoesntEndWith(vec!["-"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_starts_with_error_params() {
+    let mut params = Map::new();
+    params.assign("code", string("ACC-2017")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("code", vec![Rule::StartsWith(vec!["ORD-", "INV-"])]);
+
+    let errors = validate(&rules, params).unwrap_err();
+    let error = &errors["code"][0];
+    assert_eq!(error.kind(), ErrorKind::StartsWith);
+    assert_eq!(error.kind().code(), "starts_with");
+    assert_eq!(error.param("values"), Some(&Param::Value("ORD-, INV-".to_owned())));
+}
+
+#[test]
+fn test_affix_parse() {
+    let rules = "starts_with:a,b|ends_with:c|doesnt_start_with:d|doesnt_end_with:e,f";
+    let rules = Rule::parse_list(rules).unwrap();
+
+    match (&rules[0], &rules[1], &rules[2], &rules[3]) {
+        (&Rule::StartsWith(ref starts),
+         &Rule::EndsWith(ref ends),
+         &Rule::DoesntStartWith(ref doesnt_start),
+         &Rule::DoesntEndWith(ref doesnt_end)) => {
+            assert_eq!(*starts, vec!["a", "b"]);
+            assert_eq!(*ends, vec!["c"]);
+            assert_eq!(*doesnt_start, vec!["d"]);
+            assert_eq!(*doesnt_end, vec!["e", "f"]);
+        }
+        other => panic!("Unexpected rules {:?}", other),
+    }
+    assert!(Rule::parse("starts_with").is_err());
+}
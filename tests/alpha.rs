@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -62,7 +65,7 @@ fn test_alpha_invalid_numeric_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha").unwrap()),
                vec!["The alpha field may only contain alphabetic characters.".to_owned()]);
 }
 
@@ -77,7 +80,7 @@ fn test_alpha_invalid_whitespace() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha").unwrap()),
                vec!["The alpha field may only contain alphabetic characters.".to_owned()]);
 }
 
@@ -92,7 +95,7 @@ fn test_alpha_invalid_underscore() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha").unwrap()),
                vec!["The alpha field may only contain alphabetic characters.".to_owned()]);
 }
 
@@ -107,7 +110,7 @@ fn test_alpha_invalid_dash() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha").unwrap()),
                vec!["The alpha field may only contain alphabetic characters.".to_owned()]);
 }
 
@@ -122,7 +125,7 @@ fn test_alpha_invalid_numeric() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha").unwrap()),
                vec!["The alpha field may only contain alphabetic characters.".to_owned()]);
 }
 
@@ -165,7 +168,7 @@ fn test_alpha_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha").unwrap()),
                vec!["The alpha field may only contain alphabetic characters.".to_owned()]);
 }
 
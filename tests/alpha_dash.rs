@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -77,7 +80,7 @@ fn test_alpha_dash_invalid_whitespace() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_dash").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_dash").unwrap()),
                vec!["The alpha dash field may only contain alphanumeric characters, dashes, and underscores."
                         .to_owned()]);
 }
@@ -153,7 +156,7 @@ fn test_alpha_dash_invalid_float() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_dash").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_dash").unwrap()),
                vec!["The alpha dash field may only contain alphanumeric characters, dashes, and underscores."
                         .to_owned()]);
 }
@@ -197,7 +200,7 @@ fn test_alpha_dash_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_dash").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_dash").unwrap()),
                vec!["The alpha dash field may only contain alphanumeric characters, dashes, and underscores."
                         .to_owned()]);
 }
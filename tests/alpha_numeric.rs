@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -77,7 +80,7 @@ fn test_alpha_numeric_invalid_whitespace() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_numeric").unwrap()),
                vec!["The alpha numeric field may only contain alphanumeric characters."
                         .to_owned()]);
 }
@@ -93,7 +96,7 @@ fn test_alpha_numeric_invalid_underscore() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_numeric").unwrap()),
                vec!["The alpha numeric field may only contain alphanumeric characters."
                         .to_owned()]);
 }
@@ -109,7 +112,7 @@ fn test_alpha_numeric_invalid_dash() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_numeric").unwrap()),
                vec!["The alpha numeric field may only contain alphanumeric characters."
                         .to_owned()]);
 }
@@ -140,7 +143,7 @@ fn test_alpha_numeric_invalid_negative() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_numeric").unwrap()),
                vec!["The alpha numeric field may only contain alphanumeric characters."
                         .to_owned()]);
 }
@@ -156,7 +159,7 @@ fn test_alpha_numeric_invalid_float() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_numeric").unwrap()),
                vec!["The alpha numeric field may only contain alphanumeric characters."
                         .to_owned()]);
 }
@@ -200,7 +203,7 @@ fn test_alpha_numeric_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("alpha_numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("alpha_numeric").unwrap()),
                vec!["The alpha numeric field may only contain alphanumeric characters."
                         .to_owned()]);
 }
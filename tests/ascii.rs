@@ -0,0 +1,55 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: Value) -> Result<(), Vec<String>> {
+    let mut params = Map::new();
+    params.assign("username", value).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("username", vec![Rule::Ascii]);
+
+    validate(&rules, params)
+        .map(|_| ())
+        .map_err(|errors| errors["username"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_ascii_valid() {
+    assert_eq!(check(Value::String("john_doe-42 ~!@#".to_owned())), Ok(()));
+}
+
+#[test]
+fn test_ascii_invalid() {
+    for value in &["josé", "日本", "naïve", "emoji 😀"] {
+        assert_eq!(check(Value::String(value.to_string())),
+                   Err(vec!["The username field may only contain ASCII characters.".to_owned()]),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_ascii_invalid_type() {
+    assert!(check(Value::U64(42)).is_err());
+}
+
+#[test]
+fn test_ascii_valid_empty() {
+    assert!(check(Value::String(String::new())).is_ok());
+}
+
+#[test]
+fn test_ascii_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("username", Rule::parse_list("ascii").unwrap());
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,127 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{EmailOptions, Options, Rule, validate_with};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_attributes_field() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("dob", vec![Rule::Required]);
+
+    let options = Options::new().attribute("dob", "date of birth");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("dob").unwrap()),
+               vec!["The date of birth field is required.".to_owned()]);
+}
+
+#[test]
+fn test_attributes_nested_field() {
+    let mut user = Map::new();
+    user.assign("email", Value::String("foo".to_owned())).ok();
+    let mut params = Map::new();
+    params.assign("user", Value::Map(user)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("user.email", vec![Rule::Email(EmailOptions::new())]);
+
+    let options = Options::new().attribute("user.email", "user's email");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("user.email").unwrap()),
+               vec!["The user's email field must contain a valid email address.".to_owned()]);
+}
+
+#[test]
+fn test_attributes_wildcard_field() {
+    let mut params = Map::new();
+    params.assign("items[][qty]", Value::String("x".to_owned())).ok();
+    params.assign("items[][qty]", Value::String("y".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.qty", vec![Rule::Integer]);
+
+    let options = Options::new()
+        .attribute("items.*.qty", "item quantity")
+        .attribute("items.1.qty", "second item quantity");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(messages(errors.get("items.0.qty").unwrap()),
+               vec!["The item quantity field must be an integer.".to_owned()]);
+    assert_eq!(messages(errors.get("items.1.qty").unwrap()),
+               vec!["The second item quantity field must be an integer.".to_owned()]);
+}
+
+#[test]
+fn test_attributes_other_field() {
+    let mut params = Map::new();
+    params.assign("pw", Value::String("foo".to_owned())).ok();
+    params.assign("pw2", Value::String("bar".to_owned())).ok();
+    params.assign("colors[]", Value::String("red".to_owned())).ok();
+    params.assign("color", Value::String("green".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("pw", vec![Rule::Same("pw2"), Rule::Different("color")]);
+    rules.insert("color", vec![Rule::InArray("colors")]);
+
+    let options = Options::new()
+        .attribute("pw", "password")
+        .attribute("pw2", "password confirmation")
+        .attribute("colors", "available colors");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(messages(errors.get("pw").unwrap()),
+               vec!["The password field must match the password confirmation field.".to_owned()]);
+    assert_eq!(messages(errors.get("color").unwrap()),
+               vec!["The color field must be one of the values in the available colors field."
+                        .to_owned()]);
+}
+
+#[test]
+fn test_attributes_required_with_values() {
+    let mut params = Map::new();
+    params.assign("first_name", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("last_name", vec![Rule::RequiredWith(vec!["first_name", "middle_name"])]);
+
+    let options = Options::new()
+        .attribute("first_name", "given name")
+        .attribute("last_name", "family name")
+        .message("required_with", "The :attribute is required with :values.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("last_name").unwrap()),
+               vec!["The family name is required with given name, middle name.".to_owned()]);
+}
+
+#[test]
+fn test_attributes_wildcard_other_field() {
+    let mut params = Map::new();
+    params.assign("items[][price]", Value::U64(5)).ok();
+    params.assign("items[][confirm]", Value::U64(6)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.price", vec![Rule::Same("items.*.confirm")]);
+
+    let options = Options::new().attribute("items.*.confirm", "confirmed price");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("items.0.price").unwrap()),
+               vec!["The price field must match the confirmed price field.".to_owned()]);
+}
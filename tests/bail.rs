@@ -0,0 +1,123 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{EmailOptions, ErrorKind, Options, Rule, ValidationError, validate, validate_with};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn counted(calls: Arc<AtomicUsize>) -> Rule<'static> {
+    Rule::closure(move |_, path| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        Err(ValidationError::new(ErrorKind::Custom("expensive"), path))
+    })
+}
+
+#[test]
+fn test_bail_stops_after_failure() {
+    let params = Map::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email",
+                 vec![Rule::Bail,
+                      Rule::Required,
+                      Rule::Email(EmailOptions::new()),
+                      counted(calls.clone())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("email").unwrap()),
+               vec!["The email field is required.".to_owned()]);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_bail_anywhere_in_ruleset() {
+    let mut params = Map::new();
+    params.assign("email", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email", vec![Rule::Email(EmailOptions::new()), Rule::Min(5.into()), Rule::Bail]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("email").unwrap()),
+               vec!["The email field must contain a valid email address.".to_owned()]);
+}
+
+#[test]
+fn test_bail_valid() {
+    let mut params = Map::new();
+    params.assign("email", Value::String("foo@example.com".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email", vec![Rule::Bail, Rule::Required, Rule::Email(EmailOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_bail_only_affects_its_field() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email", vec![Rule::Bail, Rule::Required, Rule::Email(EmailOptions::new())]);
+    rules.insert("name", vec![Rule::Required, Rule::Present]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors.get("email").unwrap().len(), 1);
+    assert_eq!(errors.get("name").unwrap().len(), 2);
+}
+
+#[test]
+fn test_bail_without_bail_runs_every_rule() {
+    let params = Map::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email", vec![Rule::Required, counted(calls.clone())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().get("email").unwrap().len(), 2);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_bail_option() {
+    let params = Map::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email", vec![Rule::Required, counted(calls.clone())]);
+    rules.insert("name", vec![Rule::Required, Rule::Present]);
+
+    let result = validate_with(&rules, params, &Options::new().bail(true));
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors.get("email").unwrap().len(), 1);
+    assert_eq!(errors.get("name").unwrap().len(), 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_bail_parse() {
+    match Rule::parse("bail") {
+        Ok(Rule::Bail) => (),
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
@@ -2,6 +2,9 @@ extern crate iron_valid;
 extern crate mime;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use mime::Mime;
 use params::{File, Map, Value};
@@ -15,7 +18,7 @@ fn test_between_valid_string() {
     params.assign("between", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -30,7 +33,7 @@ fn test_between_valid_empty_string() {
     params.assign("between", Value::String("".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -45,12 +48,12 @@ fn test_between_invalid_high_string() {
     params.assign("between", Value::String("foobar".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must be between 1 and 5 characters.".to_owned()]);
 }
 
@@ -60,12 +63,12 @@ fn test_between_invalid_low_string() {
     params.assign("between", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(5, 10)]);
+    rules.insert("between", vec![Rule::Between(5.into(), 10.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must be between 5 and 10 characters.".to_owned()]);
 }
 
@@ -75,7 +78,7 @@ fn test_between_valid_u64() {
     params.assign("between", Value::U64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -89,12 +92,12 @@ fn test_between_invalid_high_u64() {
     params.assign("between", Value::U64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must be between 1 and 5.".to_owned()]);
 }
 
@@ -104,12 +107,12 @@ fn test_between_invalid_low_u64() {
     params.assign("between", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(2, 5)]);
+    rules.insert("between", vec![Rule::Between(2.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must be between 2 and 5.".to_owned()]);
 }
 
@@ -119,7 +122,7 @@ fn test_between_valid_u64_min_below_0() {
     params.assign("between", Value::U64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(-1, 5)]);
+    rules.insert("between", vec![Rule::Between((-1).into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -133,12 +136,12 @@ fn test_between_invalid_u64_max_below_0() {
     params.assign("between", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(-5, -1)]);
+    rules.insert("between", vec![Rule::Between((-5).into(), (-1).into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must be between -5 and -1.".to_owned()]);
 }
 
@@ -148,7 +151,7 @@ fn test_between_valid_i64() {
     params.assign("between", Value::I64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -162,12 +165,12 @@ fn test_between_invalid_high_i64() {
     params.assign("between", Value::I64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must be between 1 and 5.".to_owned()]);
 }
 
@@ -177,22 +180,64 @@ fn test_between_invalid_low_i64() {
     params.assign("between", Value::I64(-10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must be between 1 and 5.".to_owned()]);
 }
 
+#[test]
+fn test_between_valid_fractional_bounds() {
+    let mut params = Map::new();
+    params.assign("between", Value::F64(0.5)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("between", vec![Rule::Between(0.25.into(), 0.75.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["between"]).unwrap(), &Value::F64(0.5));
+}
+
+#[test]
+fn test_between_invalid_fractional_bounds() {
+    let mut params = Map::new();
+    params.assign("between", Value::U64(1)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("between", vec![Rule::Between(0.25.into(), 0.75.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
+               vec!["The between field must be between 0.25 and 0.75.".to_owned()]);
+}
+
+#[test]
+fn test_between_invalid_negative_bounds_for_string() {
+    let mut params = Map::new();
+    params.assign("between", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("between", vec![Rule::Between((-5).into(), (-1).into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_between_valid_array() {
     let mut params = Map::new();
     params.assign("between", Value::Array(vec![Value::U64(1), Value::U64(2)])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -207,7 +252,7 @@ fn test_between_valid_empty_array() {
     params.assign("between", Value::Array(vec![])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -224,12 +269,12 @@ fn test_between_invalid_high_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 2)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must have between 1 and 2 items.".to_owned()]);
 }
 
@@ -241,12 +286,12 @@ fn test_between_invalid_low_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(5, 8)]);
+    rules.insert("between", vec![Rule::Between(5.into(), 8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must have between 5 and 8 items.".to_owned()]);
 }
 
@@ -259,7 +304,7 @@ fn test_between_valid_object() {
     params.assign("between", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -274,7 +319,7 @@ fn test_between_valid_empty_object() {
     params.assign("between", Value::Map(Map::new())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -293,12 +338,12 @@ fn test_between_invalid_high_object() {
     params.assign("between", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 2)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must have between 1 and 2 items.".to_owned()]);
 }
 
@@ -312,12 +357,12 @@ fn test_between_invalid_low_object() {
     params.assign("between", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(5, 8)]);
+    rules.insert("between", vec![Rule::Between(5.into(), 8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must have between 5 and 8 items.".to_owned()]);
 }
 
@@ -333,7 +378,7 @@ fn test_between_valid_file() {
     params.assign("between", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 5)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
@@ -354,12 +399,12 @@ fn test_between_invalid_high_file() {
     params.assign("between", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(1, 2)]);
+    rules.insert("between", vec![Rule::Between(1.into(), 2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between must be between 1 and 2 kilobytes.".to_owned()]);
 }
 
@@ -375,12 +420,12 @@ fn test_between_invalid_low_file() {
     params.assign("between", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(5, 8)]);
+    rules.insert("between", vec![Rule::Between(5.into(), 8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between must be between 5 and 8 kilobytes.".to_owned()]);
 }
 
@@ -389,7 +434,7 @@ fn test_between_valid_blank() {
     let params = Map::new();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(0, 1)]);
+    rules.insert("between", vec![Rule::Between(0.into(), 1.into())]);
 
     let result = validate(&rules, params);
 
@@ -403,12 +448,12 @@ fn test_between_invalid_null() {
     params.assign("between", Value::Null).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("between", vec![Rule::Between(0, 1)]);
+    rules.insert("between", vec![Rule::Between(0.into(), 1.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("between").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("between").unwrap()),
                vec!["The between field must have a size between 0 and 1.".to_owned()]);
 }
 
@@ -420,7 +465,7 @@ fn test_between_valid_nested() {
     params.assign("test", Value::Map(test)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("test.between", vec![Rule::Between(1, 5)]);
+    rules.insert("test.between", vec![Rule::Between(1.into(), 5.into())]);
 
     let result = validate(&rules, params);
 
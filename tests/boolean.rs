@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -61,7 +64,7 @@ fn test_boolean_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("boolean").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("boolean").unwrap()),
                vec!["The boolean field must be a boolean.".to_owned()]);
 }
 
@@ -91,7 +94,7 @@ fn test_boolean_invalid_numeric() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("boolean").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("boolean").unwrap()),
                vec!["The boolean field must be a boolean.".to_owned()]);
 }
 
@@ -119,7 +122,7 @@ fn test_boolean_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("boolean").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("boolean").unwrap()),
                vec!["The boolean field must be a boolean.".to_owned()]);
 }
 
@@ -0,0 +1,6 @@
+use iron_valid::ValidationError;
+
+/// Returns the message of each error, for comparing against the expected messages.
+pub fn messages(errors: &[ValidationError]) -> Vec<&str> {
+    errors.iter().map(ValidationError::message).collect()
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -49,7 +52,7 @@ fn test_confirmed_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -81,7 +84,7 @@ fn test_confirmed_invalid_u64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -113,7 +116,7 @@ fn test_confirmed_invalid_i64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -145,7 +148,7 @@ fn test_confirmed_invalid_f64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -197,7 +200,7 @@ fn test_confirmed_invalid_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -251,7 +254,7 @@ fn test_confirmed_invalid_object() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -283,7 +286,7 @@ fn test_confirmed_invalid_boolean() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -311,7 +314,7 @@ fn test_confirmed_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("confirmed").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("confirmed").unwrap()),
                vec!["The confirmed field must match the confirmed confirmation.".to_owned()]);
 }
 
@@ -0,0 +1,123 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Context, ErrorKind, Options, Param, Rule, ValidationError, Validator, validate,
+                 validate_with};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+struct Prefix(&'static str);
+
+impl Validator for Prefix {
+    fn validate(&self, ctx: &Context, path: &[&str]) -> Result<Option<Value>, ValidationError> {
+        match ctx.find(path) {
+            Some(&Value::String(ref value)) if !value.starts_with(self.0) => {
+                Err(ValidationError::new(ErrorKind::Custom("prefix"), path)
+                    .with_param("prefix", Param::Value(self.0.to_owned())))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[test]
+fn test_custom_validator_valid() {
+    let mut params = Map::new();
+    params.assign("sku", Value::String("SKU-123".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sku", vec![Rule::custom(Prefix("SKU-"))]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_custom_validator_invalid() {
+    let mut params = Map::new();
+    params.assign("sku", Value::String("123".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sku", vec![Rule::custom(Prefix("SKU-"))]);
+
+    let options = Options::new().message("prefix", "The :attribute must start with :prefix.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("sku").unwrap()[0];
+    assert_eq!(error.kind(), ErrorKind::Custom("prefix"));
+    assert_eq!(error.code(), "prefix");
+    assert_eq!(error.to_string(), "The sku must start with SKU-.");
+}
+
+#[test]
+fn test_custom_default_message() {
+    let mut params = Map::new();
+    params.assign("sku", Value::String("123".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sku", vec![Rule::custom(Prefix("SKU-"))]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("sku").unwrap()),
+               vec!["The sku field is invalid.".to_owned()]);
+}
+
+#[test]
+fn test_custom_closure_transform_order() {
+    let mut params = Map::new();
+    params.assign("qty", Value::String("4".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("qty",
+                 vec![Rule::Integer,
+                      Rule::closure(|ctx, path| match ctx.find(path) {
+                          Some(&Value::I64(qty)) => Ok(Some(Value::I64(qty * 2))),
+                          Some(&Value::U64(qty)) => Ok(Some(Value::U64(qty * 2))),
+                          _ => Err(ValidationError::new(ErrorKind::Custom("doubled"), path)),
+                      }),
+                      Rule::Max(5.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("qty").unwrap()),
+               vec!["The qty field must be no greater than 5.".to_owned()]);
+}
+
+#[test]
+fn test_custom_closure_wildcard() {
+    let mut params = Map::new();
+    params.assign("items[][price]", Value::U64(5)).ok();
+    params.assign("items[][discount]", Value::U64(2)).ok();
+    params.assign("items[][price]", Value::U64(5)).ok();
+    params.assign("items[][discount]", Value::U64(8)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.discount",
+                 vec![Rule::closure(|ctx, path| {
+                          let price = ctx.resolve("items.*.price");
+                          let price = price.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+                          match (ctx.find(path), ctx.find(&price)) {
+                              (Some(&Value::U64(discount)), Some(&Value::U64(price)))
+                                  if discount > price => {
+                                  Err(ValidationError::new(ErrorKind::Custom("discount"), path))
+                              }
+                              _ => Ok(None),
+                          }
+                      })]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(!errors.contains_key("items.0.discount"));
+    assert_eq!(errors.get("items.1.discount").unwrap()[0].code(), "discount");
+}
@@ -0,0 +1,205 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn validate_value(value: &str, rule: Rule) -> Result<Map, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("date", Value::String(value.to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("date", vec![rule]);
+
+    validate(&rules, params).map_err(|errors| {
+        errors.get("date").unwrap().iter().map(|error| error.to_string()).collect()
+    })
+}
+
+#[test]
+fn test_date_valid_date() {
+    let result = validate_value("2017-03-14", Rule::Date);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["date"]).unwrap(),
+               &Value::String("2017-03-14".to_owned()));
+}
+
+#[test]
+fn test_date_valid_datetime() {
+    let result = validate_value("2017-03-14 15:09:26", Rule::Date);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["date"]).unwrap(),
+               &Value::String("2017-03-14T15:09:26".to_owned()));
+}
+
+#[test]
+fn test_date_valid_rfc3339() {
+    let result = validate_value("2017-03-14T15:09:26+01:00", Rule::Date);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["date"]).unwrap(),
+               &Value::String("2017-03-14T14:09:26Z".to_owned()));
+}
+
+#[test]
+fn test_date_valid_empty() {
+    let result = validate_value("", Rule::Date);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_date_valid_missing() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("date", vec![Rule::Date]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_date_invalid() {
+    let result = validate_value("2017-02-30", Rule::Date);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The date field must be a valid date.".to_owned()]);
+}
+
+#[test]
+fn test_date_invalid_number() {
+    let mut params = Map::new();
+    params.assign("date", Value::U64(20170314)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("date", vec![Rule::Date]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_date_format_valid() {
+    let result = validate_value("14/03/2017", Rule::DateFormat("%d/%m/%Y"));
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["date"]).unwrap(),
+               &Value::String("2017-03-14".to_owned()));
+}
+
+#[test]
+fn test_date_format_valid_time() {
+    let result = validate_value("3:09 PM", Rule::DateFormat("%I:%M %p"));
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["date"]).unwrap(),
+               &Value::String("15:09:00".to_owned()));
+}
+
+#[test]
+fn test_date_format_invalid() {
+    let result = validate_value("2017-03-14", Rule::DateFormat("%d/%m/%Y"));
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The date field must match the format %d/%m/%Y.".to_owned()]);
+}
+
+#[test]
+fn test_date_before_literal() {
+    assert!(validate_value("2017-03-13", Rule::Before("2017-03-14")).is_ok());
+    assert_eq!(validate_value("2017-03-14", Rule::Before("2017-03-14")).unwrap_err(),
+               vec!["The date field must be a date before 2017-03-14.".to_owned()]);
+    assert!(validate_value("2017-03-14", Rule::BeforeOrEqual("2017-03-14")).is_ok());
+    assert!(validate_value("2017-03-15", Rule::BeforeOrEqual("2017-03-14")).is_err());
+}
+
+#[test]
+fn test_date_after_literal() {
+    assert!(validate_value("2017-03-14T00:00:01", Rule::After("2017-03-14")).is_ok());
+    assert_eq!(validate_value("2017-03-14", Rule::After("2017-03-14")).unwrap_err(),
+               vec!["The date field must be a date after 2017-03-14.".to_owned()]);
+    assert!(validate_value("2017-03-14", Rule::AfterOrEqual("2017-03-14")).is_ok());
+    assert!(validate_value("2017-03-13", Rule::AfterOrEqual("2017-03-14")).is_err());
+}
+
+#[test]
+fn test_date_equals_literal() {
+    assert!(validate_value("2017-03-14T01:00:00+01:00", Rule::DateEquals("2017-03-14")).is_ok());
+    assert_eq!(validate_value("2017-03-15", Rule::DateEquals("2017-03-14")).unwrap_err(),
+               vec!["The date field must be a date equal to 2017-03-14.".to_owned()]);
+}
+
+#[test]
+fn test_date_relative() {
+    assert!(validate_value("2000-01-01", Rule::Before("today")).is_ok());
+    assert!(validate_value("2000-01-01", Rule::After("yesterday")).is_err());
+    assert!(validate_value("2999-01-01", Rule::After("+30 days")).is_ok());
+    assert!(validate_value("2999-01-01", Rule::Before("+2 years")).is_err());
+    assert!(validate_value("2000-01-01", Rule::Before("3 weeks ago")).is_ok());
+    assert_eq!(validate_value("2999-01-01", Rule::BeforeOrEqual("tomorrow")).unwrap_err(),
+               vec!["The date field must be a date before or equal to tomorrow.".to_owned()]);
+}
+
+#[test]
+fn test_date_other_field() {
+    let mut params = Map::new();
+    params.assign("starts_at", Value::String("2017-03-14".to_owned())).ok();
+    params.assign("ends_at", Value::String("2017-03-10".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("starts_at", vec![Rule::Date]);
+    rules.insert("ends_at", vec![Rule::Date, Rule::After("starts_at")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("ends_at").unwrap()),
+               vec!["The ends at field must be a date after starts at.".to_owned()]);
+}
+
+#[test]
+fn test_date_other_field_missing() {
+    let result = validate_value("2017-03-14", Rule::After("starts_at"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_date_wildcard_other_field() {
+    let mut params = Map::new();
+    params.assign("bookings[][from]", Value::String("2017-03-14".to_owned())).ok();
+    params.assign("bookings[][to]", Value::String("2017-03-16".to_owned())).ok();
+    params.assign("bookings[][from]", Value::String("2017-03-14".to_owned())).ok();
+    params.assign("bookings[][to]", Value::String("2017-03-12".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("bookings.*.to", vec![Rule::AfterOrEqual("bookings.*.from")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(!errors.contains_key("bookings.0.to"));
+    assert!(errors.contains_key("bookings.1.to"));
+}
+
+#[test]
+fn test_date_parse() {
+    let rules = Rule::parse_list("date|after:tomorrow|date_format:%d/%m/%Y, %H:%M").unwrap();
+
+    match (&rules[0], &rules[1], &rules[2]) {
+        (&Rule::Date, &Rule::After("tomorrow"), &Rule::DateFormat("%d/%m/%Y, %H:%M")) => (),
+        other => panic!("Unexpected rules {:?}", other),
+    }
+}
@@ -0,0 +1,117 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{ErrorKind, Param, Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: Value, min: usize, max: usize) -> Result<(), Vec<String>> {
+    let mut params = Map::new();
+    params.assign("price", value).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("price", vec![Rule::Decimal(min, max)]);
+
+    validate(&rules, params)
+        .map(|_| ())
+        .map_err(|errors| errors["price"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_decimal_valid() {
+    for value in &["10.50", "-0.99", "+3.00", ".25", "1234567890.12"] {
+        assert_eq!(check(Value::String(value.to_string()), 2, 2), Ok(()), "{}", value);
+    }
+}
+
+#[test]
+fn test_decimal_invalid() {
+    for value in &["10.5", "10", "10.500", "1e2", "10.5.0", "ten", "--1.00", "1,00"] {
+        assert_eq!(check(Value::String(value.to_string()), 2, 2),
+                   Err(vec!["The price field must have 2 decimal places.".to_owned()]),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_decimal_valid_range() {
+    assert!(check(Value::String("10".to_owned()), 0, 2).is_ok());
+    assert!(check(Value::String("10.5".to_owned()), 0, 2).is_ok());
+    assert!(check(Value::String("10.55".to_owned()), 0, 2).is_ok());
+    assert_eq!(check(Value::String("10.555".to_owned()), 0, 2),
+               Err(vec!["The price field must have 0-2 decimal places.".to_owned()]));
+}
+
+#[test]
+fn test_decimal_keeps_trailing_zeros_before_numeric() {
+    let mut params = Map::new();
+    params.assign("price", Value::String("10.50".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("price", vec![Rule::Decimal(2, 2), Rule::Numeric]);
+
+    let result = validate(&rules, params);
+
+    assert_eq!(result.unwrap().find(&["price"]), Some(&Value::F64(10.5)));
+}
+
+#[test]
+fn test_decimal_numbers() {
+    assert!(check(Value::F64(10.25), 2, 2).is_ok());
+    assert!(check(Value::F64(10.5), 2, 2).is_err());
+    assert!(check(Value::U64(10), 0, 0).is_ok());
+    assert!(check(Value::I64(-10), 1, 2).is_err());
+    assert!(check(Value::F64(f64::INFINITY), 0, 2).is_err());
+}
+
+#[test]
+fn test_decimal_error_params() {
+    let mut params = Map::new();
+    params.assign("price", Value::String("10.5".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("price", vec![Rule::Decimal(2, 4)]);
+
+    let errors = validate(&rules, params).unwrap_err();
+    let error = &errors["price"][0];
+    assert_eq!(error.kind(), ErrorKind::Decimal);
+    assert_eq!(error.param("min"), Some(&Param::Value("2".to_owned())));
+    assert_eq!(error.param("max"), Some(&Param::Value("4".to_owned())));
+}
+
+#[test]
+fn test_decimal_invalid_type() {
+    assert!(check(Value::Boolean(false), 0, 2).is_err());
+}
+
+#[test]
+fn test_decimal_valid_empty() {
+    assert!(check(Value::String(String::new()), 2, 2).is_ok());
+}
+
+#[test]
+fn test_decimal_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("price", vec![Rule::Decimal(2, 2)]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_decimal_parse() {
+    let rules = Rule::parse_list("decimal:2|decimal:1,4").unwrap();
+
+    match (&rules[0], &rules[1]) {
+        (&Rule::Decimal(2, 2), &Rule::Decimal(1, 4)) => (),
+        other => panic!("Unexpected rules {:?}", other),
+    }
+    assert!(Rule::parse("decimal").is_err());
+    assert!(Rule::parse("decimal:1,2,3").is_err());
+    assert!(Rule::parse("decimal:two").is_err());
+    assert!(Rule::parse("decimal:4,1").is_err());
+}
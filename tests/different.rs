@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -49,7 +52,7 @@ fn test_different_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -81,7 +84,7 @@ fn test_different_invalid_u64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -113,7 +116,7 @@ fn test_different_invalid_i64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -145,7 +148,7 @@ fn test_different_invalid_f64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -193,7 +196,7 @@ fn test_different_invalid_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -248,7 +251,7 @@ fn test_different_invalid_object() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -280,7 +283,7 @@ fn test_different_invalid_boolean() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -308,7 +311,7 @@ fn test_different_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("different").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("different").unwrap()),
                vec!["The different field must be different than the other field.".to_owned()]);
 }
 
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -61,7 +64,7 @@ fn test_digits_between_invalid_string_digits() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -76,7 +79,7 @@ fn test_digits_between_invalid_string_non_numeric() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -121,7 +124,7 @@ fn test_digits_between_invalid_high_u64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -136,7 +139,7 @@ fn test_digits_between_invalid_low_u64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -166,7 +169,7 @@ fn test_digits_between_invalid_high_i64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -181,7 +184,7 @@ fn test_digits_between_invalid_low_i64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -211,7 +214,7 @@ fn test_digits_between_invalid_high_f64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -226,7 +229,7 @@ fn test_digits_between_invalid_low_f64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
@@ -254,7 +257,7 @@ fn test_digits_between_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("digits").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("digits").unwrap()),
                vec!["The digits field must be a number with between 4 and 6 digits.".to_owned()]);
 }
 
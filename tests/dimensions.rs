@@ -0,0 +1,133 @@
+extern crate iron_valid;
+extern crate mime;
+extern crate params;
+
+use iron_valid::{Dimensions, ErrorKind, Param, Rule, validate};
+use mime::Mime;
+use params::{File, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn upload(path: &str, content_type: &str) -> Value {
+    Value::File(File {
+        path: PathBuf::from(path),
+        filename: None,
+        size: 0,
+        content_type: Mime::from_str(content_type).unwrap(),
+    })
+}
+
+fn check(path: &str, dimensions: Dimensions) -> bool {
+    let mut params = Map::new();
+    params.assign("image", upload(path, "application/octet-stream")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("image", vec![Rule::Dimensions(dimensions)]);
+
+    validate(&rules, params).is_ok()
+}
+
+#[test]
+fn test_dimensions_valid_formats() {
+    let sizes = [("tests/files/image.png", 3, 2),
+                 ("tests/files/image.jpg", 40, 30),
+                 ("tests/files/image.gif", 1, 1),
+                 ("tests/files/image.webp", 16, 9),
+                 ("tests/files/image.bmp", 4, 5)];
+    for &(path, width, height) in &sizes {
+        assert!(check(path, Dimensions::new().width(width).height(height)),
+                "{} should be {}x{}",
+                path,
+                width,
+                height);
+        assert!(!check(path, Dimensions::new().width(width + 1)));
+        assert!(!check(path, Dimensions::new().height(height + 1)));
+    }
+}
+
+#[test]
+fn test_dimensions_valid_bounds() {
+    let dimensions = Dimensions::new().min_width(40).max_width(100).min_height(10).max_height(30);
+
+    assert!(check("tests/files/image.jpg", dimensions));
+}
+
+#[test]
+fn test_dimensions_valid_ratio() {
+    assert!(check("tests/files/image.webp", Dimensions::new().ratio(16, 9)));
+    assert!(check("tests/files/image.jpg", Dimensions::new().ratio(4, 3)));
+}
+
+#[test]
+fn test_dimensions_invalid_ratio() {
+    assert!(!check("tests/files/image.png", Dimensions::new().ratio(16, 9)));
+}
+
+#[test]
+fn test_dimensions_invalid_reports_constraint() {
+    let mut params = Map::new();
+    params.assign("avatar", upload("tests/files/image.png", "image/png")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Dimensions(Dimensions::new().min_width(100))]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors["avatar"][0];
+    assert_eq!(error.kind(), ErrorKind::Dimensions);
+    assert_eq!(error.param("constraint"),
+               Some(&Param::Value("min_width".to_owned())));
+    assert_eq!(error.param("min_width"), Some(&Param::Value("100".to_owned())));
+    assert_eq!(error.param("width"), Some(&Param::Value("3".to_owned())));
+    assert_eq!(error.to_string(),
+               "The avatar field has invalid image dimensions.");
+}
+
+#[test]
+fn test_dimensions_invalid_not_image() {
+    assert!(!check("tests/files/document.pdf", Dimensions::new()));
+    assert!(!check("tests/files/missing.png", Dimensions::new()));
+}
+
+#[test]
+fn test_dimensions_invalid_string() {
+    let mut params = Map::new();
+    params.assign("avatar", Value::String("avatar.png".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Dimensions(Dimensions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_dimensions_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Dimensions(Dimensions::new().min_width(100))]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_dimensions_parse() {
+    let rules = Rule::parse_list("dimensions:min_width=100,max_height=500,ratio=3/2").unwrap();
+
+    match rules[0] {
+        Rule::Dimensions(dimensions) => {
+            assert_eq!(dimensions,
+                       Dimensions::new().min_width(100).max_height(500).ratio(3, 2))
+        }
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    assert!(Rule::parse("dimensions:depth=3").is_err());
+    assert!(Rule::parse("dimensions:min_width=wide").is_err());
+}
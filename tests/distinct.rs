@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -35,7 +38,7 @@ fn test_distinct_invalid_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("distinct").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("distinct").unwrap()),
                vec!["The distinct field must not contain any duplicate values.".to_owned()]);
 }
 
@@ -1,7 +1,11 @@
 extern crate iron_valid;
 extern crate params;
 
-use iron_valid::{Rule, validate};
+mod common;
+
+use common::messages;
+use iron_valid::{EmailMode, EmailOptions, Options, RecordType, Rule, StaticResolver, validate,
+                 validate_with};
 use params::{Map, Value};
 use std::collections::BTreeMap;
 
@@ -11,7 +15,7 @@ fn test_email_valid() {
     params.assign("email", Value::String("foo@bar.com".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("email", vec![Rule::Email]);
+    rules.insert("email", vec![Rule::Email(EmailOptions::new())]);
 
     let result = validate(&rules, params);
 
@@ -26,12 +30,12 @@ fn test_email_invalid_string() {
     params.assign("email", Value::String("foobar.com".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("email", vec![Rule::Email]);
+    rules.insert("email", vec![Rule::Email(EmailOptions::new())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("email").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("email").unwrap()),
                vec!["The email field must contain a valid email address.".to_owned()]);
 }
 
@@ -41,12 +45,12 @@ fn test_email_invalid_numeric() {
     params.assign("email", Value::U64(42)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("email", vec![Rule::Email]);
+    rules.insert("email", vec![Rule::Email(EmailOptions::new())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("email").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("email").unwrap()),
                vec!["The email field must contain a valid email address.".to_owned()]);
 }
 
@@ -56,7 +60,7 @@ fn test_email_valid_empty() {
     params.assign("email", Value::String("".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("email", vec![Rule::Email]);
+    rules.insert("email", vec![Rule::Email(EmailOptions::new())]);
 
     let result = validate(&rules, params);
 
@@ -70,7 +74,7 @@ fn test_email_valid_blank() {
     let params = Map::new();
 
     let mut rules = BTreeMap::new();
-    rules.insert("email", vec![Rule::Email]);
+    rules.insert("email", vec![Rule::Email(EmailOptions::new())]);
 
     let result = validate(&rules, params);
 
@@ -84,12 +88,12 @@ fn test_email_invalid_null() {
     params.assign("email", Value::Null).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("email", vec![Rule::Email]);
+    rules.insert("email", vec![Rule::Email(EmailOptions::new())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("email").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("email").unwrap()),
                vec!["The email field must contain a valid email address.".to_owned()]);
 }
 
@@ -101,7 +105,7 @@ fn test_email_nested() {
     params.assign("test", Value::Map(test)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("test.email", vec![Rule::Email]);
+    rules.insert("test.email", vec![Rule::Email(EmailOptions::new())]);
 
     let result = validate(&rules, params);
 
@@ -109,3 +113,170 @@ fn test_email_nested() {
     assert_eq!(result.unwrap().find(&["test", "email"]).unwrap(),
                &Value::String("foo@bar.com".to_owned()));
 }
+
+fn check(value: &str, options: EmailOptions) -> Result<Value, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("email", Value::String(value.to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email", vec![Rule::Email(options)]);
+
+    validate(&rules, params)
+        .map(|values| values.find(&["email"]).unwrap().clone())
+        .map_err(|errors| errors["email"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_email_rfc_valid() {
+    let valid = ["simple@example.com",
+                 "very.common@example.com",
+                 "x@example.com",
+                 "long.email-address-with-hyphens@and.subdomains.example.com",
+                 "user.name+tag+sorting@example.com",
+                 "!#$%&'*+-/=?^_`{|}~@example.org",
+                 "\"john doe\"@example.com",
+                 r#""very.(),:;<>[]\".VERY.\"very@\\ \"very\".unusual"@strange.example.com"#,
+                 "admin@mailserver1",
+                 "postmaster@[192.0.2.1]",
+                 "postmaster@[IPv6:2001:db8::1]"];
+    for value in &valid {
+        assert_eq!(check(value, EmailOptions::new()),
+                   Ok(Value::String(value.to_string())),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_email_rfc_invalid() {
+    let invalid = ["a@b.c d",
+                   "\"foo@bar.com,baz@qux.com\"",
+                   "foo@bar.com,baz@qux.com",
+                   "Abc.example.com",
+                   "A@b@c@example.com",
+                   "a\"b(c)d,e:f;g<h>i[j\\k]l@example.com",
+                   "just\"not\"right@example.com",
+                   "john..doe@example.com",
+                   ".john@example.com",
+                   "john.@example.com",
+                   "john@example..com",
+                   "john@-example.com",
+                   "john@example.com.",
+                   "john@",
+                   "@example.com",
+                   "john@[192.0.2.256]",
+                   "john@[2001:db8::1]",
+                   "\"unterminated@example.com"];
+    for value in &invalid {
+        assert_eq!(check(value, EmailOptions::new()),
+                   Err(vec!["The email field must contain a valid email address.".to_owned()]),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_email_invalid_lengths() {
+    let local = "a".repeat(65);
+    assert!(check(&format!("{}@example.com", local), EmailOptions::new()).is_err());
+    assert!(check(&format!("{}@example.com", &local[1..]), EmailOptions::new()).is_ok());
+
+    let label = "a".repeat(63);
+    let domain = [label.as_str(); 4].join(".");
+    assert!(check(&format!("abc@{}", &domain[..250]), EmailOptions::new()).is_ok());
+    assert!(check(&format!("abcd@{}", &domain[..250]), EmailOptions::new()).is_err());
+    assert!(check(&format!("a@{}.com", "b".repeat(64)), EmailOptions::new()).is_err());
+}
+
+#[test]
+fn test_email_html5() {
+    let options = EmailOptions::new().mode(EmailMode::Html5);
+
+    assert!(check("user.name+tag@example.com", options).is_ok());
+    assert!(check("admin@mailserver1", options).is_ok());
+    assert!(check(".john..doe.@example.com", options).is_ok());
+    assert!(check("\"john doe\"@example.com", options).is_err());
+    assert!(check("postmaster@[192.0.2.1]", options).is_err());
+    assert!(check("a@b.c d", options).is_err());
+    assert!(check("a@b@example.com", options).is_err());
+}
+
+#[test]
+fn test_email_no_quoted() {
+    let options = EmailOptions::new().quoted(false);
+
+    assert!(check("\"john doe\"@example.com", options).is_err());
+    assert!(check("john.doe@example.com", options).is_ok());
+}
+
+#[test]
+fn test_email_no_ip_literal() {
+    let options = EmailOptions::new().ip_literal(false);
+
+    assert!(check("postmaster@[192.0.2.1]", options).is_err());
+    assert!(check("postmaster@[IPv6:2001:db8::1]", options).is_err());
+    assert!(check("postmaster@example.com", options).is_ok());
+}
+
+#[test]
+fn test_email_idn_punycode() {
+    assert_eq!(check("user@münchen.de", EmailOptions::new()),
+               Ok(Value::String("user@xn--mnchen-3ya.de".to_owned())));
+    assert_eq!(check("user@例え.jp", EmailOptions::new().mode(EmailMode::Html5)),
+               Ok(Value::String("user@xn--r8jz45g.jp".to_owned())));
+    assert!(check("usér@example.com", EmailOptions::new()).is_err());
+}
+
+#[test]
+fn test_email_lowercase_domain() {
+    let options = EmailOptions::new().lowercase_domain(true);
+
+    assert_eq!(check("John.Doe@Example.COM", options),
+               Ok(Value::String("John.Doe@example.com".to_owned())));
+    assert_eq!(check("John.Doe@Example.COM", EmailOptions::new()),
+               Ok(Value::String("John.Doe@Example.COM".to_owned())));
+    assert_eq!(check("postmaster@[IPv6:2001:DB8::1]", options),
+               Ok(Value::String("postmaster@[IPv6:2001:DB8::1]".to_owned())));
+}
+
+#[test]
+fn test_email_parse() {
+    let rules = Rule::parse_list("email:html5,lowercase_domain|email:no_quoted,no_ip_literal,dns")
+        .unwrap();
+
+    match (&rules[0], &rules[1]) {
+        (&Rule::Email(html5), &Rule::Email(rfc)) => {
+            assert_eq!(html5,
+                       EmailOptions::new().mode(EmailMode::Html5).lowercase_domain(true));
+            assert_eq!(rfc, EmailOptions::new().quoted(false).ip_literal(false).dns(true));
+        }
+        other => panic!("Unexpected rules {:?}", other),
+    }
+    assert!(Rule::parse("email:spoof").is_err());
+}
+
+#[test]
+fn test_email_dns() {
+    let resolver = StaticResolver::new()
+        .record("example.com", RecordType::Mx, "mail.example.com")
+        .record("web.example.com", RecordType::A, "192.0.2.1")
+        .record("nomail.example.com", RecordType::Mx, ".")
+        .record("nomail.example.com", RecordType::A, "192.0.2.1");
+    let options = Options::new().resolver(resolver);
+    let check = |value: &str| {
+        let mut params = Map::new();
+        params.assign("email", Value::String(value.to_owned())).ok();
+
+        let mut rules = BTreeMap::new();
+        rules.insert("email", vec![Rule::Email(EmailOptions::new().dns(true))]);
+
+        validate_with(&rules, params, &options).is_ok()
+    };
+
+    assert!(check("john@example.com"));
+    // Without MX records, mail is delivered to the domain's own address
+    assert!(check("john@web.example.com"));
+    assert!(!check("john@nomail.example.com"));
+    assert!(!check("john@missing.example.com"));
+    assert!(check("john@[192.0.2.1]"));
+}
@@ -0,0 +1,136 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{ErrorKind, Param, Rule, ValidationError, ValueType, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_error_structured_max_string() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("foobar".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Max(3.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("name").unwrap()[0];
+    assert_eq!(error.kind(), ErrorKind::Max);
+    assert_eq!(error.code(), "max");
+    assert_eq!(error.field(), "name");
+    assert_eq!(error.param("max"), Some(&Param::Value("3".to_owned())));
+    assert_eq!(error.value_type(), Some(ValueType::String));
+    assert_eq!(error.message_key(), "max.string");
+    assert_eq!(error.to_string(),
+               "The name field must be no greater than 3 characters.");
+}
+
+#[test]
+fn test_error_structured_max_numeric() {
+    let mut params = Map::new();
+    params.assign("count", Value::U64(4)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("count", vec![Rule::Max(3.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("count").unwrap()[0];
+    assert_eq!(error.value_type(), Some(ValueType::U64));
+    assert_eq!(error.message_key(), "max.numeric");
+    assert_eq!(error.to_string(), "The count field must be no greater than 3.");
+}
+
+#[test]
+fn test_error_structured_missing_value() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("user.first_name", vec![Rule::Required]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("user.first_name").unwrap()[0];
+    assert_eq!(error.kind(), ErrorKind::Required);
+    assert_eq!(error.field(), "user.first_name");
+    assert_eq!(error.value_type(), None);
+    assert!(error.params().is_empty());
+    assert_eq!(error.to_string(), "The first name field is required.");
+}
+
+#[test]
+fn test_error_structured_other_field() {
+    let mut params = Map::new();
+    params.assign("password", Value::String("foo".to_owned())).ok();
+    params.assign("repeat_password", Value::String("bar".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("password", vec![Rule::Same("repeat_password")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("password").unwrap()[0];
+    assert_eq!(error.code(), "same");
+    assert_eq!(error.param("other"),
+               Some(&Param::Fields(vec!["repeat_password".to_owned()])));
+    assert_eq!(error.to_string(),
+               "The password field must match the repeat password field.");
+}
+
+#[test]
+fn test_error_structured_values() {
+    let mut params = Map::new();
+    params.assign("color", Value::String("green".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("color",
+                 vec![Rule::In(vec![Value::String("red".to_owned()),
+                                    Value::String("blue".to_owned())])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("color").unwrap()[0];
+    assert_eq!(error.kind(), ErrorKind::In);
+    assert_eq!(error.param("values"),
+               Some(&Param::Values(vec![Value::String("red".to_owned()),
+                                        Value::String("blue".to_owned())])));
+}
+
+#[test]
+fn test_error_structured_wildcard_field() {
+    let mut params = Map::new();
+    params.assign("items[][qty]", Value::String("1".to_owned())).ok();
+    params.assign("items[][qty]", Value::String("many".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.qty", vec![Rule::Integer]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("items.1.qty").unwrap()[0];
+    assert_eq!(error.field(), "items.1.qty");
+    assert_eq!(error.kind(), ErrorKind::Integer);
+    assert_eq!(error.value_type(), Some(ValueType::String));
+}
+
+#[test]
+fn test_error_display_without_validate() {
+    let error = ValidationError::new(ErrorKind::Min, &["user", "first_name"])
+        .with_param("min", Param::Value("2".to_owned()));
+
+    assert_eq!(error.message(), "");
+    assert_eq!(error.to_string(), "The first name field must have at least a size of 2.");
+}
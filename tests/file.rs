@@ -0,0 +1,73 @@
+extern crate iron_valid;
+extern crate mime;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Rule, validate};
+use mime::Mime;
+use params::{File, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[test]
+fn test_file_valid() {
+    let mut params = Map::new();
+    let file = File {
+        path: PathBuf::from("tests/files/document.txt"),
+        filename: Some("document.txt".to_owned()),
+        size: 31,
+        content_type: Mime::from_str("text/plain").unwrap(),
+    };
+    params.assign("upload", Value::File(file.clone())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::File]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["upload"]).unwrap(), &Value::File(file));
+}
+
+#[test]
+fn test_file_invalid_string() {
+    let mut params = Map::new();
+    params.assign("upload", Value::String("document.txt".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::File]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("upload").unwrap()),
+               vec!["The upload field must be a file.".to_owned()]);
+}
+
+#[test]
+fn test_file_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::File]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_file_valid_empty() {
+    let mut params = Map::new();
+    params.assign("upload", Value::String("".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::File]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,149 @@
+extern crate iron_valid;
+extern crate mime;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{FileSize, ParseError, Rule, validate};
+use mime::Mime;
+use params::{File, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn upload(size: u64) -> Value {
+    Value::File(File {
+        path: PathBuf::from("files/temp_size_3914b.txt"),
+        filename: None,
+        size,
+        content_type: Mime::from_str("text/plain").unwrap(),
+    })
+}
+
+#[test]
+fn test_file_size_parse_units() {
+    assert_eq!("2048".parse::<FileSize>().unwrap().as_bytes(), 2048);
+    assert_eq!("2.5MB".parse::<FileSize>().unwrap().as_bytes(), 2_500_000);
+    assert_eq!("512KiB".parse::<FileSize>().unwrap().as_bytes(), 524_288);
+    assert_eq!("1.5 gib".parse::<FileSize>().unwrap().as_bytes(), 1_610_612_736);
+    assert_eq!("10B".parse::<FileSize>().unwrap().as_bytes(), 10);
+    assert_eq!(".5KB".parse::<FileSize>().unwrap().as_bytes(), 500);
+    assert_eq!("1KiB".parse::<FileSize>().unwrap(), FileSize::bytes(1024));
+}
+
+#[test]
+fn test_file_size_parse_invalid() {
+    assert_eq!("2.5XB".parse::<FileSize>(),
+               Err(ParseError::InvalidFileSize("2.5XB".to_owned())));
+    assert!("MB".parse::<FileSize>().is_err());
+    assert!("1.5B".parse::<FileSize>().is_err());
+    assert!("1.2.3MB".parse::<FileSize>().is_err());
+    assert!("-1KB".parse::<FileSize>().is_err());
+    assert!("99999999999TB".parse::<FileSize>().is_err());
+}
+
+#[test]
+fn test_file_size_display() {
+    assert_eq!("2.5MB".parse::<FileSize>().unwrap().to_string(), "2.5 MB");
+    assert_eq!("512KiB".parse::<FileSize>().unwrap().to_string(), "512 KiB");
+    assert_eq!(FileSize::bytes(1).to_string(), "1 byte");
+    assert_eq!(FileSize::bytes(999).to_string(), "999 bytes");
+    assert_eq!(FileSize::bytes(3914).to_string(), "3.91 KB");
+    assert_eq!(FileSize::bytes(2_000_000).to_string(), "2 MB");
+}
+
+#[test]
+fn test_max_file_size_valid() {
+    let mut params = Map::new();
+    params.assign("upload", upload(2_500_000)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MaxFileSize("2.5MB".parse().unwrap())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_max_file_size_invalid() {
+    let mut params = Map::new();
+    params.assign("upload", upload(2_500_001)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MaxFileSize("2.5MB".parse().unwrap())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("upload").unwrap()),
+               vec!["The upload must be no larger than 2.5 MB.".to_owned()]);
+}
+
+#[test]
+fn test_min_file_size_valid() {
+    let mut params = Map::new();
+    params.assign("upload", upload(1024)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MinFileSize(FileSize::bytes(1024))]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_min_file_size_invalid() {
+    let mut params = Map::new();
+    params.assign("upload", upload(1023)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MinFileSize("1KiB".parse().unwrap())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("upload").unwrap()),
+               vec!["The upload must be at least 1 KiB.".to_owned()]);
+}
+
+#[test]
+fn test_max_file_size_invalid_string() {
+    let mut params = Map::new();
+    params.assign("upload", Value::String("upload.txt".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MaxFileSize(FileSize::bytes(1024))]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_max_file_size_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MaxFileSize(FileSize::bytes(1024))]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_file_size_parse_rules() {
+    let rules = Rule::parse_list("max_file_size:2.5MB|min_file_size:512").unwrap();
+
+    match (&rules[0], &rules[1]) {
+        (&Rule::MaxFileSize(max), &Rule::MinFileSize(min)) => {
+            assert_eq!(max.as_bytes(), 2_500_000);
+            assert_eq!(min.as_bytes(), 512);
+        }
+        other => panic!("Unexpected rules {:?}", other),
+    }
+    assert!(Rule::parse("max_file_size:big").is_err());
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -61,7 +64,7 @@ fn test_filled_invalid_empty_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("filled").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("filled").unwrap()),
                vec!["The filled field must be filled.".to_owned()]);
 }
 
@@ -119,7 +122,7 @@ fn test_filled_invalid_empty_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("filled").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("filled").unwrap()),
                vec!["The filled field must be filled.".to_owned()]);
 }
 
@@ -152,7 +155,7 @@ fn test_filled_invalid_empty_object() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("filled").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("filled").unwrap()),
                vec!["The filled field must be filled.".to_owned()]);
 }
 
@@ -180,7 +183,7 @@ fn test_filled_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("filled").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("filled").unwrap()),
                vec!["The filled field must be filled.".to_owned()]);
 }
 
@@ -0,0 +1,163 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn validate_values(value: Value, other: Value) -> Result<Map, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("end_page", value).ok();
+    params.assign("start_page", other).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("end_page", vec![Rule::Gt("start_page")]);
+
+    validate(&rules, params).map_err(|errors| {
+        errors.get("end_page").unwrap().iter().map(|error| error.to_string()).collect()
+    })
+}
+
+#[test]
+fn test_gt_valid_numeric() {
+    assert!(validate_values(Value::U64(5), Value::U64(4)).is_ok());
+    assert!(validate_values(Value::I64(-3), Value::I64(-4)).is_ok());
+    assert!(validate_values(Value::F64(4.5), Value::U64(4)).is_ok());
+    assert!(validate_values(Value::U64(0), Value::I64(-1)).is_ok());
+}
+
+#[test]
+fn test_gt_valid_large_numbers() {
+    assert!(validate_values(Value::U64(u64::MAX), Value::U64(u64::MAX - 1))
+        .is_ok());
+    assert!(validate_values(Value::U64(9_007_199_254_740_993), Value::F64(9_007_199_254_740_992.0))
+        .is_ok());
+}
+
+#[test]
+fn test_gt_invalid_numeric() {
+    let result = validate_values(Value::U64(4), Value::U64(4));
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The end page field must be greater than 4.".to_owned()]);
+    assert!(validate_values(Value::F64(3.9), Value::U64(4)).is_err());
+    assert!(validate_values(Value::I64(-1), Value::U64(0)).is_err());
+    assert!(validate_values(Value::F64(f64::NAN), Value::U64(0)).is_err());
+}
+
+#[test]
+fn test_gt_valid_string() {
+    let result = validate_values(Value::String("abcd".to_owned()),
+                                 Value::String("abc".to_owned()));
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_gt_invalid_string() {
+    let result = validate_values(Value::String("ab".to_owned()), Value::String("a".to_owned()));
+    assert!(result.is_ok());
+
+    let result = validate_values(Value::String("a".to_owned()), Value::String("a".to_owned()));
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The end page field must be greater than 1 character.".to_owned()]);
+}
+
+#[test]
+fn test_gt_array() {
+    let mut params = Map::new();
+    params.assign("guests[]", Value::U64(1)).ok();
+    params.assign("rooms[]", Value::U64(1)).ok();
+    params.assign("rooms[]", Value::U64(2)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("guests", vec![Rule::Gt("rooms")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("guests").unwrap()),
+               vec!["The guests field must have more than 2 items.".to_owned()]);
+}
+
+#[test]
+fn test_gt_invalid_different_types() {
+    let result = validate_values(Value::U64(10), Value::String("five".to_owned()));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_gt_numeric_string_other() {
+    assert!(validate_values(Value::U64(10), Value::String("9".to_owned())).is_ok());
+    assert!(validate_values(Value::F64(2.5), Value::String("-2.75".to_owned())).is_ok());
+    assert_eq!(validate_values(Value::U64(10), Value::String("10".to_owned())).unwrap_err(),
+               vec!["The end page field must be greater than 10.".to_owned()]);
+}
+
+#[test]
+fn test_gt_invalid_missing_other() {
+    let mut params = Map::new();
+    params.assign("end_page", Value::U64(5)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("end_page", vec![Rule::Gt("start_page")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("end_page").unwrap()),
+               vec!["The end page field must be greater than start page.".to_owned()]);
+}
+
+#[test]
+fn test_gt_valid_missing() {
+    let mut params = Map::new();
+    params.assign("start_page", Value::U64(5)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("end_page", vec![Rule::Gt("start_page")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_gt_after_integer() {
+    let mut params = Map::new();
+    params.assign("last_page", Value::String("12".to_owned())).ok();
+    params.assign("first_page", Value::String("3".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("first_page", vec![Rule::Integer]);
+    rules.insert("last_page", vec![Rule::Integer, Rule::Gt("first_page")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_gt_strings_other_sorts_after() {
+    let mut params = Map::new();
+    params.assign("end_page", Value::String("10".to_owned())).ok();
+    params.assign("start_page", Value::String("9".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("end_page", vec![Rule::Integer, Rule::Gt("start_page")]);
+    rules.insert("start_page", vec![Rule::Integer, Rule::Gt("end_page")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(!errors.contains_key("end_page"));
+    assert_eq!(messages(errors.get("start_page").unwrap()),
+               vec!["The start page field must be greater than 10.".to_owned()]);
+}
@@ -0,0 +1,84 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn validate_values(value: Value, other: Value) -> Result<Map, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("max_guests", value).ok();
+    params.assign("min_guests", other).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("max_guests", vec![Rule::Gte("min_guests")]);
+
+    validate(&rules, params).map_err(|errors| {
+        errors.get("max_guests").unwrap().iter().map(|error| error.to_string()).collect()
+    })
+}
+
+#[test]
+fn test_gte_valid() {
+    assert!(validate_values(Value::U64(4), Value::U64(4)).is_ok());
+    assert!(validate_values(Value::U64(5), Value::I64(4)).is_ok());
+    assert!(validate_values(Value::F64(4.0), Value::U64(4)).is_ok());
+}
+
+#[test]
+fn test_gte_invalid() {
+    let result = validate_values(Value::U64(3), Value::U64(4));
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The max guests field must be greater than or equal to 4.".to_owned()]);
+}
+
+#[test]
+fn test_gte_strings() {
+    let mut params = Map::new();
+    params.assign("max_guests", Value::String("4".to_owned())).ok();
+    params.assign("min_guests", Value::String("4".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("max_guests", vec![Rule::Integer, Rule::Gte("min_guests")]);
+    rules.insert("min_guests", vec![Rule::Integer]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    let values = result.unwrap();
+    assert_eq!(values.find(&["max_guests"]).unwrap(), &Value::U64(4));
+    assert_eq!(values.find(&["min_guests"]).unwrap(), &Value::U64(4));
+}
+
+#[test]
+fn test_gte_strings_invalid() {
+    let result = validate_values(Value::U64(3), Value::String("4".to_owned()));
+
+    assert_eq!(result.unwrap_err(),
+               vec!["The max guests field must be greater than or equal to 4.".to_owned()]);
+}
+
+#[test]
+fn test_gte_wildcard() {
+    let mut params = Map::new();
+    params.assign("rooms[][min]", Value::U64(2)).ok();
+    params.assign("rooms[][max]", Value::U64(4)).ok();
+    params.assign("rooms[][min]", Value::U64(3)).ok();
+    params.assign("rooms[][max]", Value::U64(2)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("rooms.*.max", vec![Rule::Gte("rooms.*.min")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(!errors.contains_key("rooms.0.max"));
+    assert_eq!(messages(errors.get("rooms.1.max").unwrap()),
+               vec!["The max field must be greater than or equal to 3.".to_owned()]);
+}
@@ -0,0 +1,91 @@
+extern crate iron_valid;
+extern crate mime;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{ErrorKind, Rule, validate};
+use mime::Mime;
+use params::{File, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn upload(path: &str, content_type: &str) -> Value {
+    Value::File(File {
+        path: PathBuf::from(path),
+        filename: None,
+        size: 0,
+        content_type: Mime::from_str(content_type).unwrap(),
+    })
+}
+
+#[test]
+fn test_image_valid_png() {
+    let mut params = Map::new();
+    params.assign("avatar", upload("tests/files/image.png", "image/png")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Image]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_image_invalid_svg() {
+    let mut params = Map::new();
+    params.assign("avatar", upload("tests/files/image.svg", "image/svg+xml")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Image]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err()["avatar"][0].kind(), ErrorKind::Image);
+}
+
+#[test]
+fn test_image_invalid_type() {
+    let mut params = Map::new();
+    params.assign("avatar", upload("tests/files/document.pdf", "application/pdf")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Image]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("avatar").unwrap()),
+               vec!["The avatar field must be an image.".to_owned()]);
+}
+
+#[test]
+fn test_image_invalid_mismatch() {
+    let mut params = Map::new();
+    params.assign("avatar", upload("tests/files/image.gif", "image/png")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Image]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err()["avatar"][0].kind(), ErrorKind::MimeMismatch);
+}
+
+#[test]
+fn test_image_invalid_string() {
+    let mut params = Map::new();
+    params.assign("avatar", Value::String("avatar.png".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("avatar", vec![Rule::Image]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -37,7 +40,7 @@ fn test_in_array_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("in").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("in").unwrap()),
                vec!["The in field must be one of the values in the other field.".to_owned()]);
 }
 
@@ -52,7 +55,7 @@ fn test_in_array_invalid_other_blank() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("in").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("in").unwrap()),
                vec!["The in field must be one of the values in the other field.".to_owned()]);
 }
 
@@ -87,7 +90,7 @@ fn test_in_array_invalid_numeric() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("in").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("in").unwrap()),
                vec!["The in field must be one of the values in the other field.".to_owned()]);
 }
 
@@ -139,7 +142,7 @@ fn test_in_array_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("in").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("in").unwrap()),
                vec!["The in field must be one of the values in the other field.".to_owned()]);
 }
 
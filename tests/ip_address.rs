@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -48,7 +51,7 @@ fn test_ip_address_invalid_out_of_range() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("ip").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("ip").unwrap()),
                vec!["The ip field must contain a valid IP address.".to_owned()]);
 }
 
@@ -63,7 +66,7 @@ fn test_ip_address_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("ip").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("ip").unwrap()),
                vec!["The ip field must contain a valid IP address.".to_owned()]);
 }
 
@@ -78,7 +81,7 @@ fn test_ip_address_invalid_numeric() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("ip").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("ip").unwrap()),
                vec!["The ip field must contain a valid IP address.".to_owned()]);
 }
 
@@ -121,7 +124,7 @@ fn test_ip_address_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("ip").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("ip").unwrap()),
                vec!["The ip field must contain a valid IP address.".to_owned()]);
 }
 
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -31,7 +34,7 @@ fn test_json_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("json").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("json").unwrap()),
                vec!["The json field must contain a valid JSON string.".to_owned()]);
 }
 
@@ -46,7 +49,7 @@ fn test_json_invalid_numeric() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("json").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("json").unwrap()),
                vec!["The json field must contain a valid JSON string.".to_owned()]);
 }
 
@@ -89,7 +92,7 @@ fn test_json_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("json").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("json").unwrap()),
                vec!["The json field must contain a valid JSON string.".to_owned()]);
 }
 
@@ -0,0 +1,156 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Length, Options, Rule, validate, validate_with};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_length_counts_characters_by_default() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("Zoë".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Size(3.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_counts_characters_in_japanese() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("山田太郎".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Max(4.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_invalid_characters() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("山田太郎".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Max(3.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("name").unwrap()),
+               vec!["The name field must be no greater than 3 characters.".to_owned()]);
+}
+
+#[test]
+fn test_length_graphemes() {
+    let mut params = Map::new();
+    params.assign("emoji", Value::String("👍🏽".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("emoji", vec![Rule::Length(Length::Graphemes), Rule::Size(1.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_characters_in_grapheme() {
+    let mut params = Map::new();
+    params.assign("emoji", Value::String("👍🏽".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("emoji", vec![Rule::Size(2.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_bytes() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("Zoë".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Max(3.into()), Rule::Length(Length::Bytes)]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("name").unwrap()),
+               vec!["The name field must be no greater than 3 characters.".to_owned()]);
+}
+
+#[test]
+fn test_length_between_graphemes() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("e\u{301}e\u{301}".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name",
+                 vec![Rule::Length(Length::Graphemes), Rule::Between(1.into(), 2.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_compare_other_field() {
+    let mut params = Map::new();
+    params.assign("short", Value::String("Zoë".to_owned())).ok();
+    params.assign("long", Value::String("Zoey".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("short", vec![Rule::Lt("long")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_option() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("Zoë".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Size(4.into())]);
+
+    let result = validate_with(&rules, params, &Options::new().length(Length::Bytes));
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_rule_overrides_option() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("Zoë".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Length(Length::Characters), Rule::Size(3.into())]);
+
+    let result = validate_with(&rules, params, &Options::new().length(Length::Bytes));
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_length_parse() {
+    let rules = Rule::parse_list("length:graphemes|max:1").unwrap();
+
+    match rules[0] {
+        Rule::Length(Length::Graphemes) => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    assert!(Rule::parse("length:words").is_err());
+}
@@ -0,0 +1,57 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: Value) -> Result<(), Vec<String>> {
+    let mut params = Map::new();
+    params.assign("slug", value).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("slug", vec![Rule::Lowercase]);
+
+    validate(&rules, params)
+        .map(|_| ())
+        .map_err(|errors| errors["slug"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_lowercase_valid() {
+    for value in &["hello-world", "straße", "ελληνικά", "οδος", "123_!?"] {
+        assert_eq!(check(Value::String(value.to_string())), Ok(()), "{}", value);
+    }
+}
+
+#[test]
+fn test_lowercase_invalid() {
+    for value in &["Hello", "STRASSE", "Ελληνικά", "ǅ"] {
+        assert_eq!(check(Value::String(value.to_string())),
+                   Err(vec!["The slug field must be lowercase.".to_owned()]),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_lowercase_invalid_type() {
+    assert!(check(Value::Boolean(true)).is_err());
+}
+
+#[test]
+fn test_lowercase_valid_empty() {
+    assert!(check(Value::String(String::new())).is_ok());
+}
+
+#[test]
+fn test_lowercase_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("slug", Rule::parse_list("lowercase").unwrap());
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,35 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn validate_values(value: Value, other: Value) -> Result<Map, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("discount", value).ok();
+    params.assign("price", other).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("discount", vec![Rule::Lt("price")]);
+
+    validate(&rules, params).map_err(|errors| {
+        errors.get("discount").unwrap().iter().map(|error| error.to_string()).collect()
+    })
+}
+
+#[test]
+fn test_lt_valid() {
+    assert!(validate_values(Value::U64(3), Value::U64(4)).is_ok());
+    assert!(validate_values(Value::I64(-1), Value::U64(0)).is_ok());
+    assert!(validate_values(Value::F64(3.99), Value::U64(4)).is_ok());
+}
+
+#[test]
+fn test_lt_invalid() {
+    let result = validate_values(Value::F64(4.5), Value::F64(4.5));
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The discount field must be less than 4.5.".to_owned()]);
+}
@@ -0,0 +1,46 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn validate_values(value: Value, other: Value) -> Result<Map, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("nickname", value).ok();
+    params.assign("name", other).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nickname", vec![Rule::Lte("name")]);
+
+    validate(&rules, params).map_err(|errors| {
+        errors.get("nickname").unwrap().iter().map(|error| error.to_string()).collect()
+    })
+}
+
+#[test]
+fn test_lte_valid() {
+    assert!(validate_values(Value::String("abc".to_owned()), Value::String("abc".to_owned()))
+        .is_ok());
+    assert!(validate_values(Value::U64(4), Value::F64(4.0)).is_ok());
+}
+
+#[test]
+fn test_lte_invalid() {
+    let result = validate_values(Value::String("abcd".to_owned()),
+                                 Value::String("abc".to_owned()));
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The nickname field must be less than or equal to 3 characters.".to_owned()]);
+}
+
+#[test]
+fn test_lte_parse() {
+    let rules = Rule::parse_list("gt:a|gte:b|lt:c|lte:d").unwrap();
+
+    match (&rules[0], &rules[1], &rules[2], &rules[3]) {
+        (&Rule::Gt("a"), &Rule::Gte("b"), &Rule::Lt("c"), &Rule::Lte("d")) => (),
+        other => panic!("Unexpected rules {:?}", other),
+    }
+}
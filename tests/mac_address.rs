@@ -0,0 +1,135 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{MacAddressOptions, MacNotation, Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: &str, options: MacAddressOptions) -> Result<Value, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("mac", Value::String(value.to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("mac", vec![Rule::MacAddress(options)]);
+
+    validate(&rules, params)
+        .map(|values| values.find(&["mac"]).unwrap().clone())
+        .map_err(|errors| errors["mac"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_mac_address_valid_notations() {
+    let valid = ["00:1a:2b:3c:4d:5e",
+                 "00-1A-2B-3C-4D-5E",
+                 "001a.2b3c.4d5e",
+                 "001A2B3C4D5E",
+                 "00:1a:2b:ff:fe:3c:4d:5e",
+                 "001a.2bff.fe3c.4d5e",
+                 "001a2bfffe3c4d5e"];
+    for value in &valid {
+        assert_eq!(check(value, MacAddressOptions::new()),
+                   Ok(Value::String(value.to_string())),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_mac_address_invalid() {
+    let invalid = ["00:1a:2b:3c:4d",
+                   "00:1a:2b:3c:4d:5e:6f",
+                   "00:1a-2b:3c:4d:5e",
+                   "0:1a:2b:3c:4d:5e:f",
+                   "00:1a:2b:3c:4d:5g",
+                   "001a.2b3c.4d5",
+                   "001a2b3c4d5",
+                   "00::1a:2b:3c:4d:5e",
+                   "éé:1a:2b:3c:4d:5e"];
+    for value in &invalid {
+        assert_eq!(check(value, MacAddressOptions::new()),
+                   Err(vec!["The mac field must be a valid MAC address.".to_owned()]),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_mac_address_notation() {
+    let value = "00-1A-2B-3C-4D-5E";
+    let expected = [(MacNotation::Colon, "00:1a:2b:3c:4d:5e"),
+                    (MacNotation::Hyphen, "00-1a-2b-3c-4d-5e"),
+                    (MacNotation::Dot, "001a.2b3c.4d5e"),
+                    (MacNotation::Bare, "001a2b3c4d5e")];
+    for &(notation, expected) in &expected {
+        assert_eq!(check(value, MacAddressOptions::new().notation(notation)),
+                   Ok(Value::String(expected.to_owned())));
+    }
+}
+
+#[test]
+fn test_mac_address_notation_eui64() {
+    let result = check("001a2bfffe3c4d5e",
+                       MacAddressOptions::new().notation(MacNotation::Dot));
+
+    assert_eq!(result, Ok(Value::String("001a.2bff.fe3c.4d5e".to_owned())));
+}
+
+#[test]
+fn test_mac_address_unicast() {
+    let options = MacAddressOptions::new().unicast(true);
+
+    assert!(check("00:1a:2b:3c:4d:5e", options).is_ok());
+    assert!(check("01:00:5e:00:00:fb", options).is_err());
+    assert!(check("01:00:5e:00:00:fb", MacAddressOptions::new()).is_ok());
+}
+
+#[test]
+fn test_mac_address_universal() {
+    let options = MacAddressOptions::new().universal(true);
+
+    assert!(check("00:1a:2b:3c:4d:5e", options).is_ok());
+    assert!(check("02:1a:2b:3c:4d:5e", options).is_err());
+    assert!(check("02:1a:2b:3c:4d:5e", MacAddressOptions::new()).is_ok());
+}
+
+#[test]
+fn test_mac_address_invalid_type() {
+    let mut params = Map::new();
+    params.assign("mac", Value::U64(0x001a2b3c4d5e)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("mac", vec![Rule::MacAddress(MacAddressOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_mac_address_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("mac", vec![Rule::MacAddress(MacAddressOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mac_address_parse() {
+    let rules = Rule::parse_list("mac_address:unicast,universal,dot").unwrap();
+
+    match rules[0] {
+        Rule::MacAddress(options) => {
+            assert_eq!(options,
+                       MacAddressOptions::new()
+                           .unicast(true)
+                           .universal(true)
+                           .notation(MacNotation::Dot))
+        }
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    assert!(Rule::parse("mac_address:multicast").is_err());
+}
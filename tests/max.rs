@@ -2,6 +2,9 @@ extern crate iron_valid;
 extern crate mime;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use mime::Mime;
 use params::{File, Map, Value};
@@ -15,7 +18,7 @@ fn test_max_valid_string() {
     params.assign("size", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(3)]);
+    rules.insert("size", vec![Rule::Max(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -30,7 +33,7 @@ fn test_max_valid_empty_string() {
     params.assign("size", Value::String("".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(2)]);
+    rules.insert("size", vec![Rule::Max(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -45,12 +48,12 @@ fn test_max_invalid_high_string() {
     params.assign("size", Value::String("foobar".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(2)]);
+    rules.insert("size", vec![Rule::Max(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be no greater than 2 characters.".to_owned()]);
 }
 
@@ -60,7 +63,7 @@ fn test_max_valid_low_string() {
     params.assign("size", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(5)]);
+    rules.insert("size", vec![Rule::Max(5.into())]);
 
     let result = validate(&rules, params);
 
@@ -75,7 +78,7 @@ fn test_max_valid_u64() {
     params.assign("size", Value::U64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(3)]);
+    rules.insert("size", vec![Rule::Max(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -89,12 +92,12 @@ fn test_max_invalid_high_u64() {
     params.assign("size", Value::U64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(5)]);
+    rules.insert("size", vec![Rule::Max(5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be no greater than 5.".to_owned()]);
 }
 
@@ -104,7 +107,7 @@ fn test_max_valid_low_u64() {
     params.assign("size", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(5)]);
+    rules.insert("size", vec![Rule::Max(5.into())]);
 
     let result = validate(&rules, params);
 
@@ -118,22 +121,90 @@ fn test_max_invalid_u64_below_0() {
     params.assign("size", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(-5)]);
+    rules.insert("size", vec![Rule::Max((-5).into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be no greater than -5.".to_owned()]);
 }
 
+#[test]
+fn test_max_valid_fractional_bound() {
+    let mut params = Map::new();
+    params.assign("price", Value::F64(99.99)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("price", vec![Rule::Max(99.99.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["price"]).unwrap(), &Value::F64(99.99));
+}
+
+#[test]
+fn test_max_invalid_fractional_bound() {
+    let mut params = Map::new();
+    params.assign("price", Value::U64(100)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("price", vec![Rule::Max(99.99.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("price").unwrap()),
+               vec!["The price field must be no greater than 99.99.".to_owned()]);
+}
+
+#[test]
+fn test_max_valid_large_u64() {
+    let mut params = Map::new();
+    params.assign("size", Value::U64(u64::MAX - 1)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("size", vec![Rule::Max(u64::MAX.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_max_invalid_large_u64() {
+    let mut params = Map::new();
+    params.assign("size", Value::U64(u64::MAX)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("size", vec![Rule::Max((u64::MAX - 1).into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_max_invalid_nan() {
+    let mut params = Map::new();
+    params.assign("size", Value::F64(f64::NAN)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("size", vec![Rule::Max(5.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_max_valid_i64() {
     let mut params = Map::new();
     params.assign("size", Value::I64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(3)]);
+    rules.insert("size", vec![Rule::Max(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -147,12 +218,12 @@ fn test_max_invalid_high_i64() {
     params.assign("size", Value::I64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(1)]);
+    rules.insert("size", vec![Rule::Max(1.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be no greater than 1.".to_owned()]);
 }
 
@@ -162,7 +233,7 @@ fn test_max_valid_low_i64() {
     params.assign("size", Value::I64(-10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(4)]);
+    rules.insert("size", vec![Rule::Max(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -176,7 +247,7 @@ fn test_max_valid_array() {
     params.assign("size", Value::Array(vec![Value::U64(1), Value::U64(2)])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(2)]);
+    rules.insert("size", vec![Rule::Max(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -191,7 +262,7 @@ fn test_max_valid_empty_array() {
     params.assign("size", Value::Array(vec![])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(4)]);
+    rules.insert("size", vec![Rule::Max(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -208,12 +279,12 @@ fn test_max_invalid_high_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(2)]);
+    rules.insert("size", vec![Rule::Max(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have no greater than 2 items.".to_owned()]);
 }
 
@@ -225,7 +296,7 @@ fn test_max_valid_low_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(8)]);
+    rules.insert("size", vec![Rule::Max(8.into())]);
 
     let result = validate(&rules, params);
 
@@ -245,7 +316,7 @@ fn test_max_valid_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(4)]);
+    rules.insert("size", vec![Rule::Max(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -259,7 +330,7 @@ fn test_max_valid_empty_object() {
     params.assign("size", Value::Map(Map::new())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(4)]);
+    rules.insert("size", vec![Rule::Max(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -278,12 +349,12 @@ fn test_max_invalid_high_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(2)]);
+    rules.insert("size", vec![Rule::Max(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have no greater than 2 items.".to_owned()]);
 }
 
@@ -297,7 +368,7 @@ fn test_max_valid_low_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(8)]);
+    rules.insert("size", vec![Rule::Max(8.into())]);
 
     let result = validate(&rules, params);
 
@@ -317,7 +388,7 @@ fn test_max_valid_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(3)]);
+    rules.insert("size", vec![Rule::Max(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -325,6 +396,46 @@ fn test_max_valid_file() {
     assert_eq!(result.unwrap().find(&["size"]).unwrap(), &Value::File(file));
 }
 
+#[test]
+fn test_max_invalid_partial_kilobyte_file() {
+    let mut params = Map::new();
+    let file = File {
+        path: PathBuf::from("files/temp_size_3914b.txt"),
+        filename: None,
+        size: 3914,
+        content_type: Mime::from_str("text/plain").unwrap(),
+    };
+    params.assign("size", Value::File(file.clone())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("size", vec![Rule::Max(3.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
+               vec!["The size must be no greater than 3 kilobytes.".to_owned()]);
+}
+
+#[test]
+fn test_max_valid_fractional_file() {
+    let mut params = Map::new();
+    let file = File {
+        path: PathBuf::from("files/temp_size_3914b.txt"),
+        filename: None,
+        size: 3914,
+        content_type: Mime::from_str("text/plain").unwrap(),
+    };
+    params.assign("size", Value::File(file.clone())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("size", vec![Rule::Max(3.83.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
 #[test]
 fn test_max_invalid_high_file() {
     let mut params = Map::new();
@@ -337,12 +448,12 @@ fn test_max_invalid_high_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(2)]);
+    rules.insert("size", vec![Rule::Max(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size must be no greater than 2 kilobytes.".to_owned()]);
 }
 
@@ -358,7 +469,7 @@ fn test_max_valid_low_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(8)]);
+    rules.insert("size", vec![Rule::Max(8.into())]);
 
     let result = validate(&rules, params);
 
@@ -371,7 +482,7 @@ fn test_max_valid_blank() {
     let params = Map::new();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(4)]);
+    rules.insert("size", vec![Rule::Max(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -385,12 +496,12 @@ fn test_max_invalid_null() {
     params.assign("size", Value::Null).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Max(4)]);
+    rules.insert("size", vec![Rule::Max(4.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have no greater than a size of 4.".to_owned()]);
 }
 
@@ -402,7 +513,7 @@ fn test_max_valid_nested() {
     params.assign("test", Value::Map(test)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("test.size", vec![Rule::Max(3)]);
+    rules.insert("test.size", vec![Rule::Max(3.into())]);
 
     let result = validate(&rules, params);
 
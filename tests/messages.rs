@@ -0,0 +1,123 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Options, Rule, validate_with};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_messages_field_rule() {
+    let mut params = Map::new();
+    params.assign("password", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("password", vec![Rule::Min(8.into())]);
+
+    let options = Options::new()
+        .message("password.min", "Your :attribute needs at least :min characters.")
+        .message("min", "Too short.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("password").unwrap()),
+               vec!["Your password needs at least 8 characters.".to_owned()]);
+}
+
+#[test]
+fn test_messages_rule() {
+    let mut params = Map::new();
+    params.assign("password", Value::String("foo".to_owned())).ok();
+    params.assign("username", Value::String("ab".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("password", vec![Rule::Min(8.into())]);
+    rules.insert("username", vec![Rule::Min(3.into())]);
+
+    let options = Options::new()
+        .message("password.min", "Your :attribute needs at least :min characters.")
+        .message("min", "The :attribute is too short.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("username").unwrap()),
+               vec!["The username is too short.".to_owned()]);
+}
+
+#[test]
+fn test_messages_wildcard_rule() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("email", vec![Rule::Required]);
+
+    let options = Options::new().message("*.required", "Please fill in the :attribute.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("email").unwrap()),
+               vec!["Please fill in the email.".to_owned()]);
+}
+
+#[test]
+fn test_messages_wildcard_field() {
+    let mut params = Map::new();
+    params.assign("items[][qty]", Value::U64(1)).ok();
+    params.assign("items[][qty]", Value::U64(20)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.qty", vec![Rule::Between(1.into(), 10.into())]);
+
+    let options = Options::new().message("items.*.qty.between",
+                                         "Each :attribute must be from :min to :max.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("items.1.qty").unwrap()),
+               vec!["Each qty must be from 1 to 10.".to_owned()]);
+}
+
+#[test]
+fn test_messages_other_and_values() {
+    let mut params = Map::new();
+    params.assign("password", Value::String("foo".to_owned())).ok();
+    params.assign("password_repeat", Value::String("bar".to_owned())).ok();
+    params.assign("color", Value::String("green".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("password", vec![Rule::Same("password_repeat")]);
+    rules.insert("color",
+                 vec![Rule::In(vec![Value::String("red".to_owned()), Value::U64(1)])]);
+
+    let options = Options::new()
+        .message("same", ":attribute and :other differ.")
+        .message("in", "Pick one of :values, not :unknown.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(messages(errors.get("password").unwrap()),
+               vec!["password and password repeat differ.".to_owned()]);
+    assert_eq!(messages(errors.get("color").unwrap()),
+               vec!["Pick one of [\"red\", 1], not :unknown.".to_owned()]);
+}
+
+#[test]
+fn test_messages_default() {
+    let mut params = Map::new();
+    params.assign("size", Value::String("foobar".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("size", vec![Rule::Size(3.into())]);
+
+    let options = Options::new().message("password.size", "Unused.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    let error = &errors.get("size").unwrap()[0];
+    assert_eq!(error.code(), "size");
+    assert_eq!(error.to_string(), "The size field must be 3 characters.");
+}
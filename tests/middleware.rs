@@ -0,0 +1,77 @@
+extern crate iron;
+extern crate iron_valid;
+extern crate params;
+
+use iron::prelude::*;
+use iron::status;
+use iron_valid::{Options, Rule, Validate, Validated};
+use params::Value;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+fn handler(req: &mut Request) -> IronResult<Response> {
+    let values = req.extensions.get::<Validated>().unwrap();
+    match values.find(&["age"]) {
+        Some(&Value::U64(age)) => Ok(Response::with((status::Ok, format!("age {}", age)))),
+        _ => Ok(Response::with((status::Ok, "no age"))),
+    }
+}
+
+fn request(validate: Validate, query: &str) -> String {
+    let mut chain = Chain::new(handler);
+    chain.link_before(validate);
+    let mut listening = Iron::new(chain).http("127.0.0.1:0").unwrap();
+
+    let mut stream = TcpStream::connect(listening.socket).unwrap();
+    write!(stream,
+           "GET /?{} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+           query)
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    listening.close().unwrap();
+    response
+}
+
+fn rules() -> BTreeMap<&'static str, Vec<Rule<'static>>> {
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Required]);
+    rules.insert("age", vec![Rule::Integer]);
+    rules
+}
+
+#[test]
+fn test_middleware_valid() {
+    let response = request(Validate::new(rules), "name=foo&age=30");
+
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.ends_with("age 30"));
+}
+
+#[test]
+fn test_middleware_invalid() {
+    let response = request(Validate::new(rules), "age=foo");
+
+    assert!(response.starts_with("HTTP/1.1 422 Unprocessable Entity"));
+    assert!(response.contains("Content-Type: application/json"));
+    assert!(response.ends_with("{\"errors\":{\"age\":[\"The age field must be an integer.\"],\
+                                \"name\":[\"The name field is required.\"]}}"));
+}
+
+#[test]
+fn test_middleware_options_and_response() {
+    let validate = Validate::new(rules)
+        .options(Options::new().message("required", "Missing :attribute."))
+        .response(|errors| {
+            let messages = errors.values()
+                .flat_map(|messages| messages.iter().map(|error| error.to_string()))
+                .collect::<Vec<String>>();
+            Response::with((status::BadRequest, messages.join(" ")))
+        });
+    let response = request(validate, "age=1");
+
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+    assert!(response.ends_with("Missing name."));
+}
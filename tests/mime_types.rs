@@ -0,0 +1,157 @@
+extern crate iron_valid;
+extern crate mime;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{ErrorKind, Rule, validate};
+use mime::Mime;
+use params::{File, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn upload(path: &str, content_type: &str) -> Value {
+    Value::File(File {
+        path: PathBuf::from(path),
+        filename: None,
+        size: 0,
+        content_type: Mime::from_str(content_type).unwrap(),
+    })
+}
+
+#[test]
+fn test_mime_types_valid() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/image.png", "image/png")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["image/png", "image/gif"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mime_types_valid_wildcard() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/image.gif", "image/gif")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["image/*"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mime_types_valid_wildcard_case() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/image.gif", "image/gif")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["Image/*"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mime_types_valid_svg_long_prolog() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/image_long_prolog.svg", "image/svg+xml")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["image/svg+xml"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mime_types_valid_parameters() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/document.txt", "text/plain; charset=utf-8"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["text/plain"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mime_types_invalid_declared() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/document.pdf", "application/pdf")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["image/png", "image/gif"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("upload").unwrap()),
+               vec!["The upload field must be a file of type: image/png, image/gif."
+                        .to_owned()]);
+}
+
+#[test]
+fn test_mime_types_invalid_mismatch() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/document.pdf", "image/png")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["image/png"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors["upload"][0].kind(), ErrorKind::MimeMismatch);
+    assert_eq!(errors["upload"][0].code(), "mime_mismatch");
+}
+
+#[test]
+fn test_mime_types_invalid_mismatch_unsniffable() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/document.txt", "image/png")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["image/png"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err()["upload"][0].kind(), ErrorKind::MimeMismatch);
+}
+
+#[test]
+fn test_mime_types_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::MimeTypes(vec!["image/png"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mime_types_parse() {
+    let rules = Rule::parse_list("mimetypes:image/png,image/*").unwrap();
+
+    match rules[0] {
+        Rule::MimeTypes(ref types) => assert_eq!(*types, vec!["image/png", "image/*"]),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+}
@@ -0,0 +1,202 @@
+extern crate iron_valid;
+extern crate mime;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{ErrorKind, Rule, validate};
+use mime::Mime;
+use params::{File, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn upload(path: &str, filename: &str, content_type: &str) -> Value {
+    Value::File(File {
+        path: PathBuf::from(path),
+        filename: Some(filename.to_owned()),
+        size: 0,
+        content_type: Mime::from_str(content_type).unwrap(),
+    })
+}
+
+#[test]
+fn test_mimes_valid() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/document.pdf", "report.pdf", "application/pdf"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["pdf", "docx"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mimes_valid_container() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/document.docx",
+                       "Report.DOCX",
+                       "application/octet-stream"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["pdf", "docx"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mimes_valid_unsniffable() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/document.txt", "notes.txt", "text/plain"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["txt"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mimes_valid_text_like_signature() {
+    let mut params = Map::new();
+    params.assign("table", upload("tests/files/table.csv", "table.csv", "text/csv")).ok();
+    params.assign("page", upload("tests/files/page.html", "page.html", "text/html")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("table", vec![Rule::Mimes(vec!["csv"])]);
+    rules.insert("page", vec![Rule::Mimes(vec!["html"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mimes_valid_mpeg2_audio() {
+    let mut params = Map::new();
+    params.assign("upload", upload("tests/files/audio.mp3", "song.mp3", "audio/mpeg")).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["mp3"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mimes_invalid_extension() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/image.png", "photo.png", "image/png"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["pdf", "docx"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("upload").unwrap()),
+               vec!["The upload field must be a file of type: pdf, docx.".to_owned()]);
+}
+
+#[test]
+fn test_mimes_invalid_mismatch() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/image.png", "report.pdf", "application/pdf"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["pdf"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors["upload"][0].kind(), ErrorKind::MimeMismatch);
+    assert_eq!(messages(&errors["upload"]),
+               vec!["The contents of the upload field do not match its file type.".to_owned()]);
+}
+
+#[test]
+fn test_mimes_invalid_disguised_text() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/image.gif", "notes.txt", "text/plain"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["txt"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err()["upload"][0].kind(), ErrorKind::MimeMismatch);
+}
+
+#[test]
+fn test_mimes_invalid_missing_file() {
+    let mut params = Map::new();
+    params.assign("upload",
+                upload("tests/files/missing.pdf", "report.pdf", "application/pdf"))
+        .ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["pdf"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err()["upload"][0].kind(), ErrorKind::Mimes);
+}
+
+#[test]
+fn test_mimes_invalid_string() {
+    let mut params = Map::new();
+    params.assign("upload", Value::String("report.pdf".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["pdf"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_mimes_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("upload", vec![Rule::Mimes(vec!["pdf"])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_mimes_parse() {
+    let rules = Rule::parse_list("file|mimes:pdf,docx").unwrap();
+
+    match (&rules[0], &rules[1]) {
+        (&Rule::File, &Rule::Mimes(ref extensions)) => {
+            assert_eq!(*extensions, vec!["pdf", "docx"])
+        }
+        other => panic!("Unexpected rules {:?}", other),
+    }
+}
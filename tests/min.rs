@@ -2,6 +2,9 @@ extern crate iron_valid;
 extern crate mime;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use mime::Mime;
 use params::{File, Map, Value};
@@ -15,7 +18,7 @@ fn test_min_valid_string() {
     params.assign("size", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(3)]);
+    rules.insert("size", vec![Rule::Min(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -30,7 +33,7 @@ fn test_min_valid_empty_string() {
     params.assign("size", Value::String("".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(2)]);
+    rules.insert("size", vec![Rule::Min(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -45,7 +48,7 @@ fn test_min_valid_high_string() {
     params.assign("size", Value::String("foobar".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(2)]);
+    rules.insert("size", vec![Rule::Min(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -60,12 +63,12 @@ fn test_min_invalid_low_string() {
     params.assign("size", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(5)]);
+    rules.insert("size", vec![Rule::Min(5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be at least 5 characters.".to_owned()]);
 }
 
@@ -75,7 +78,7 @@ fn test_min_valid_u64() {
     params.assign("size", Value::U64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(3)]);
+    rules.insert("size", vec![Rule::Min(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -89,7 +92,7 @@ fn test_min_valid_high_u64() {
     params.assign("size", Value::U64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(5)]);
+    rules.insert("size", vec![Rule::Min(5.into())]);
 
     let result = validate(&rules, params);
 
@@ -103,12 +106,12 @@ fn test_min_invalid_low_u64() {
     params.assign("size", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(5)]);
+    rules.insert("size", vec![Rule::Min(5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be at least 5.".to_owned()]);
 }
 
@@ -118,7 +121,7 @@ fn test_min_valid_u64_below_0() {
     params.assign("size", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(-5)]);
+    rules.insert("size", vec![Rule::Min((-5).into())]);
 
     let result = validate(&rules, params);
 
@@ -132,7 +135,7 @@ fn test_min_valid_i64() {
     params.assign("size", Value::I64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(3)]);
+    rules.insert("size", vec![Rule::Min(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -146,7 +149,7 @@ fn test_min_valid_high_i64() {
     params.assign("size", Value::I64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(1)]);
+    rules.insert("size", vec![Rule::Min(1.into())]);
 
     let result = validate(&rules, params);
 
@@ -160,12 +163,12 @@ fn test_min_invalid_low_i64() {
     params.assign("size", Value::I64(-10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(4)]);
+    rules.insert("size", vec![Rule::Min(4.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be at least 4.".to_owned()]);
 }
 
@@ -175,7 +178,7 @@ fn test_min_valid_array() {
     params.assign("size", Value::Array(vec![Value::U64(1), Value::U64(2)])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(2)]);
+    rules.insert("size", vec![Rule::Min(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -190,7 +193,7 @@ fn test_min_valid_empty_array() {
     params.assign("size", Value::Array(vec![])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(4)]);
+    rules.insert("size", vec![Rule::Min(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -207,7 +210,7 @@ fn test_min_valid_high_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(2)]);
+    rules.insert("size", vec![Rule::Min(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -224,12 +227,12 @@ fn test_min_invalid_low_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(8)]);
+    rules.insert("size", vec![Rule::Min(8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have at least 8 items.".to_owned()]);
 }
 
@@ -244,7 +247,7 @@ fn test_min_valid_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(4)]);
+    rules.insert("size", vec![Rule::Min(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -258,7 +261,7 @@ fn test_min_valid_empty_object() {
     params.assign("size", Value::Map(Map::new())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(4)]);
+    rules.insert("size", vec![Rule::Min(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -277,7 +280,7 @@ fn test_min_valid_high_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(2)]);
+    rules.insert("size", vec![Rule::Min(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -295,12 +298,12 @@ fn test_min_invalid_low_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(8)]);
+    rules.insert("size", vec![Rule::Min(8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have at least 8 items.".to_owned()]);
 }
 
@@ -316,7 +319,7 @@ fn test_min_valid_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(3)]);
+    rules.insert("size", vec![Rule::Min(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -336,7 +339,7 @@ fn test_min_valid_high_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(2)]);
+    rules.insert("size", vec![Rule::Min(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -356,12 +359,12 @@ fn test_min_invalid_low_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(8)]);
+    rules.insert("size", vec![Rule::Min(8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size must be at least 8 kilobytes.".to_owned()]);
 }
 
@@ -370,7 +373,7 @@ fn test_min_valid_blank() {
     let params = Map::new();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(4)]);
+    rules.insert("size", vec![Rule::Min(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -384,12 +387,12 @@ fn test_min_invalid_null() {
     params.assign("size", Value::Null).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Min(4)]);
+    rules.insert("size", vec![Rule::Min(4.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have at least a size of 4.".to_owned()]);
 }
 
@@ -401,7 +404,7 @@ fn test_min_valid_nested() {
     params.assign("test", Value::Map(test)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("test.size", vec![Rule::Min(3)]);
+    rules.insert("test.size", vec![Rule::Min(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -0,0 +1,104 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{Number, Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: Value, step: Number) -> Result<(), Vec<String>> {
+    let mut params = Map::new();
+    params.assign("amount", value).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("amount", vec![Rule::MultipleOf(step)]);
+
+    validate(&rules, params)
+        .map(|_| ())
+        .map_err(|errors| errors["amount"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_multiple_of_valid_integer() {
+    assert!(check(Value::U64(36), 12.into()).is_ok());
+    assert!(check(Value::I64(-24), 12.into()).is_ok());
+    assert!(check(Value::U64(0), 12.into()).is_ok());
+    assert!(check(Value::U64(36), (-12).into()).is_ok());
+}
+
+#[test]
+fn test_multiple_of_invalid_integer() {
+    assert_eq!(check(Value::U64(30), 12.into()),
+               Err(vec!["The amount field must be a multiple of 12.".to_owned()]));
+}
+
+#[test]
+fn test_multiple_of_valid_decimal_step() {
+    // 0.3 % 0.1 is not zero in floating point
+    assert!(check(Value::F64(0.3), 0.1.into()).is_ok());
+    assert!(check(Value::F64(19.95), 0.05.into()).is_ok());
+    assert!(check(Value::U64(3), 0.25.into()).is_ok());
+    assert!(check(Value::F64(1.15), 0.05.into()).is_ok());
+}
+
+#[test]
+fn test_multiple_of_invalid_decimal_step() {
+    assert_eq!(check(Value::F64(19.99), 0.05.into()),
+               Err(vec!["The amount field must be a multiple of 0.05.".to_owned()]));
+    assert!(check(Value::F64(0.35), 0.1.into()).is_err());
+}
+
+#[test]
+fn test_multiple_of_valid_string() {
+    let mut params = Map::new();
+    params.assign("amount", Value::String("12.50".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("amount", vec![Rule::MultipleOf(0.25.into())]);
+
+    let result = validate(&rules, params);
+
+    assert_eq!(result.unwrap().find(&["amount"]),
+               Some(&Value::String("12.50".to_owned())));
+}
+
+#[test]
+fn test_multiple_of_invalid_string() {
+    assert!(check(Value::String("12.55".to_owned()), 0.25.into()).is_err());
+    assert!(check(Value::String("twelve".to_owned()), 0.25.into()).is_err());
+}
+
+#[test]
+fn test_multiple_of_invalid_zero_step() {
+    assert!(check(Value::U64(0), 0.into()).is_err());
+    assert!(check(Value::U64(5), 0.0.into()).is_err());
+}
+
+#[test]
+fn test_multiple_of_invalid_type() {
+    assert!(check(Value::Boolean(true), 1.into()).is_err());
+    assert!(check(Value::F64(f64::NAN), 1.into()).is_err());
+}
+
+#[test]
+fn test_multiple_of_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("amount", vec![Rule::MultipleOf(5.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_multiple_of_parse() {
+    let rules = Rule::parse_list("multiple_of:0.05").unwrap();
+
+    match rules[0] {
+        Rule::MultipleOf(step) => assert_eq!(step, 0.05.into()),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    assert!(Rule::parse("multiple_of").is_err());
+    assert!(Rule::parse("multiple_of:nickel").is_err());
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -33,7 +36,7 @@ fn test_not_in_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("in").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("in").unwrap()),
                vec!["The in field must not be among the options: [\"2\", 1].".to_owned()]);
 }
 
@@ -64,7 +67,7 @@ fn test_not_in_invalid_numeric() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("in").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("in").unwrap()),
                vec!["The in field must not be among the options: [\"2\", 1].".to_owned()]);
 }
 
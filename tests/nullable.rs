@@ -0,0 +1,123 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_nullable_valid_null() {
+    let mut params = Map::new();
+    params.assign("nullable", Value::Null).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nullable", vec![Rule::Nullable, Rule::Integer, Rule::Max(5.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["nullable"]).unwrap(), &Value::Null);
+}
+
+#[test]
+fn test_nullable_valid_missing() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nullable", vec![Rule::Nullable, Rule::Accepted]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["nullable"]), None);
+}
+
+#[test]
+fn test_nullable_skips_required() {
+    let mut params = Map::new();
+    params.assign("nullable", Value::Null).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nullable", vec![Rule::Required, Rule::Nullable]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_nullable_valid_value() {
+    let mut params = Map::new();
+    params.assign("nullable", Value::String("3".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nullable", vec![Rule::Nullable, Rule::Integer]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["nullable"]).unwrap(), &Value::U64(3));
+}
+
+#[test]
+fn test_nullable_invalid_value() {
+    let mut params = Map::new();
+    params.assign("nullable", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nullable", vec![Rule::Nullable, Rule::Integer]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("nullable").unwrap()),
+               vec!["The nullable field must be an integer.".to_owned()]);
+}
+
+#[test]
+fn test_nullable_invalid_empty_string() {
+    let mut params = Map::new();
+    params.assign("nullable", Value::String("".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nullable", vec![Rule::Nullable, Rule::Required]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("nullable").unwrap()),
+               vec!["The nullable field is required.".to_owned()]);
+}
+
+#[test]
+fn test_nullable_invalid_null_without_nullable() {
+    let mut params = Map::new();
+    params.assign("nullable", Value::Null).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("nullable", vec![Rule::Max(5.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_nullable_wildcard() {
+    let mut params = Map::new();
+    params.assign("items[]", Value::Null).ok();
+    params.assign("items[]", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*", vec![Rule::Nullable, Rule::Integer]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(!errors.contains_key("items.0"));
+    assert!(errors.contains_key("items.1"));
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -45,7 +48,7 @@ fn test_numeric_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("numeric").unwrap()),
                vec!["The numeric field must be numeric.".to_owned()]);
 }
 
@@ -105,7 +108,7 @@ fn test_numeric_invalid_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("numeric").unwrap()),
                vec!["The numeric field must be numeric.".to_owned()]);
 }
 
@@ -124,7 +127,7 @@ fn test_numeric_invalid_object() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("numeric").unwrap()),
                vec!["The numeric field must be numeric.".to_owned()]);
 }
 
@@ -152,7 +155,7 @@ fn test_numeric_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("numeric").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("numeric").unwrap()),
                vec!["The numeric field must be numeric.".to_owned()]);
 }
 
@@ -0,0 +1,236 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{EmailOptions, Number, ParseError, Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_parse_list_simple() {
+    let rules = Rule::parse_list("required|email|max:255").unwrap();
+
+    assert_eq!(rules.len(), 3);
+    match (&rules[0], &rules[1], &rules[2]) {
+        (&Rule::Required, &Rule::Email(options), &Rule::Max(Number::Unsigned(255))) => {
+            assert_eq!(options, EmailOptions::new())
+        }
+        other => panic!("Unexpected rules {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_list_empty() {
+    let rules = Rule::parse_list("").unwrap();
+
+    assert!(rules.is_empty());
+}
+
+#[test]
+fn test_parse_field_arguments() {
+    let rules = Rule::parse_list("required_if:type,business|same:other|in_array:allowed.*")
+        .unwrap();
+
+    match rules[0] {
+        Rule::RequiredIf("type", ref values) if *values ==
+                                                 vec![Value::String("business".to_owned())] => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    match rules[1] {
+        Rule::Same("other") => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    match rules[2] {
+        Rule::InArray("allowed") => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_list_arguments() {
+    let rules = Rule::parse_list("in:foo,bar|required_with:a,b.c|between:-1,10").unwrap();
+
+    match rules[0] {
+        Rule::In(ref options) => {
+            assert_eq!(*options,
+                       vec![Value::String("foo".to_owned()), Value::String("bar".to_owned())])
+        }
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    match rules[1] {
+        Rule::RequiredWith(ref others) => assert_eq!(*others, vec!["a", "b.c"]),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    match rules[2] {
+        Rule::Between(Number::Signed(-1), Number::Unsigned(10)) => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_regex_keeps_commas() {
+    let rules = Rule::parse_list("regex:^[a-z]{1,3}$|alpha").unwrap();
+
+    match rules[0] {
+        Rule::Regex(ref pattern) if pattern == "^[a-z]{1,3}$" => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_regex_delimiters() {
+    let rules = Rule::parse_list("regex:/^[a-z]+$/|regex:#^[a-z]+$#i|regex:~^a.b$~su").unwrap();
+
+    let patterns = rules.iter()
+        .map(|rule| match *rule {
+            Rule::Regex(ref pattern) => pattern.to_string(),
+            ref other => panic!("Unexpected rule {:?}", other),
+        })
+        .collect::<Vec<String>>();
+    assert_eq!(patterns, vec!["^[a-z]+$", "(?i)^[a-z]+$", "(?s)^a.b$"]);
+}
+
+#[test]
+fn test_parse_regex_delimiters_validate() {
+    let mut params = Map::new();
+    params.assign("name", Value::String("Foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", Rule::parse_list("regex:/^[a-z]+$/i").unwrap());
+    assert!(validate(&rules, params.clone()).is_ok());
+
+    rules.insert("name", Rule::parse_list("regex:/^[a-z]+$/").unwrap());
+    assert_eq!(messages(validate(&rules, params).unwrap_err().get("name").unwrap()),
+               vec!["The name field must match the pattern \"^[a-z]+$\".".to_owned()]);
+}
+
+#[test]
+fn test_parse_regex_invalid_delimiters() {
+    assert_eq!(Rule::parse("regex:/^[a-z]+$/g").unwrap_err(),
+               ParseError::InvalidArgument {
+                   rule: "regex".to_owned(),
+                   argument: "/^[a-z]+$/g".to_owned(),
+               });
+    assert!(Rule::parse("regex:/^[a-z]+$").is_err());
+    assert_eq!(Rule::parse("regex:/[/").unwrap_err(),
+               ParseError::InvalidArgument {
+                   rule: "regex".to_owned(),
+                   argument: "/[/".to_owned(),
+               });
+    assert!(Rule::parse("regex:^(a").is_err());
+}
+
+#[test]
+fn test_parse_required_if_values() {
+    let rules = Rule::parse_list("required_if:type,a,b,c|required_unless:type,a").unwrap();
+
+    let strings = |values: &[&str]| {
+        values.iter().map(|value| Value::String(value.to_string())).collect::<Vec<Value>>()
+    };
+    match rules[0] {
+        Rule::RequiredIf("type", ref values) if *values == strings(&["a", "b", "c"]) => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    match rules[1] {
+        Rule::RequiredUnless("type", ref values) if *values == strings(&["a"]) => (),
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    assert!(Rule::parse("required_if:type").is_err());
+}
+
+#[test]
+fn test_parse_unknown_rule() {
+    let result = Rule::parse_list("required|foo");
+
+    assert_eq!(result.unwrap_err(), ParseError::UnknownRule("foo".to_owned()));
+}
+
+#[test]
+fn test_parse_unsupported_rule() {
+    let result = Rule::parse_list("unique:users");
+
+    assert_eq!(result.unwrap_err(),
+               ParseError::UnsupportedRule("unique".to_owned()));
+}
+
+#[test]
+fn test_parse_wrong_arity() {
+    let result = Rule::parse_list("required:foo");
+
+    assert_eq!(result.unwrap_err(),
+               ParseError::WrongArity {
+                   rule: "required".to_owned(),
+                   expected: "no arguments",
+                   found: 1,
+               });
+
+    let result = Rule::parse_list("between:1");
+
+    assert_eq!(result.unwrap_err(),
+               ParseError::WrongArity {
+                   rule: "between".to_owned(),
+                   expected: "2 arguments",
+                   found: 1,
+               });
+}
+
+#[test]
+fn test_parse_invalid_integer() {
+    let result = Rule::parse_list("digits:ten");
+
+    let err = result.unwrap_err();
+    assert_eq!(err,
+               ParseError::InvalidInteger {
+                   rule: "digits".to_owned(),
+                   argument: "ten".to_owned(),
+               });
+    assert_eq!(err.to_string(),
+               "The \"digits\" rule expects an integer, but \"ten\" was given");
+}
+
+#[test]
+fn test_parse_invalid_number() {
+    let result = Rule::parse_list("max:ten");
+
+    let err = result.unwrap_err();
+    assert_eq!(err,
+               ParseError::InvalidNumber {
+                   rule: "max".to_owned(),
+                   argument: "ten".to_owned(),
+               });
+    assert_eq!(err.to_string(),
+               "The \"max\" rule expects a number, but \"ten\" was given");
+}
+
+#[test]
+fn test_parse_fractional_and_large_bounds() {
+    let rules = Rule::parse_list("min:0.5|max:18446744073709551615").unwrap();
+
+    match (&rules[0], &rules[1]) {
+        (&Rule::Min(min), &Rule::Max(max)) => {
+            assert_eq!(min, Number::Float(0.5));
+            assert_eq!(max, Number::Unsigned(u64::MAX));
+        }
+        other => panic!("Unexpected rules {:?}", other),
+    }
+    assert!(Rule::parse_list("max:NaN").is_err());
+}
+
+#[test]
+fn test_parse_list_validate() {
+    let mut params = Map::new();
+    params.assign("type", Value::String("business".to_owned())).ok();
+    params.assign("company", Value::String("".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("company",
+                 Rule::parse_list("required_if:type,business|max:255").unwrap());
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("company").unwrap()),
+               vec!["The company field is required.".to_owned()]);
+}
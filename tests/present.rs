@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -89,7 +92,7 @@ fn test_present_invalid_blank() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("present").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("present").unwrap()),
                vec!["The present field must be present.".to_owned()]);
 }
 
@@ -104,7 +107,7 @@ fn test_present_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("present").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("present").unwrap()),
                vec!["The present field must be present.".to_owned()]);
 }
 
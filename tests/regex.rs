@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -11,7 +14,7 @@ fn test_regex_valid_string() {
     params.assign("regex", Value::String("12345".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
@@ -26,12 +29,12 @@ fn test_regex_invalid_string_negative() {
     params.assign("regex", Value::String("-12345".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("regex").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("regex").unwrap()),
                vec!["The regex field must match the pattern \"^\\d+$\".".to_owned()]);
 }
 
@@ -41,12 +44,12 @@ fn test_regex_invalid_string_float() {
     params.assign("regex", Value::String("1234.56".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("regex").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("regex").unwrap()),
                vec!["The regex field must match the pattern \"^\\d+$\".".to_owned()]);
 }
 
@@ -56,12 +59,12 @@ fn test_regex_invalid_string_non_numeric() {
     params.assign("regex", Value::String("fooba".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("regex").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("regex").unwrap()),
                vec!["The regex field must match the pattern \"^\\d+$\".".to_owned()]);
 }
 
@@ -71,12 +74,12 @@ fn test_regex_invalid_empty_string() {
     params.assign("regex", Value::String("".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("regex").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("regex").unwrap()),
                vec!["The regex field must match the pattern \"^\\d+$\".".to_owned()]);
 }
 
@@ -86,7 +89,7 @@ fn test_regex_valid_u64() {
     params.assign("regex", Value::U64(12345)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
@@ -101,12 +104,12 @@ fn test_regex_invalid_i64() {
     params.assign("regex", Value::I64(-12345)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("regex").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("regex").unwrap()),
                vec!["The regex field must match the pattern \"^\\d+$\".".to_owned()]);
 }
 
@@ -116,12 +119,12 @@ fn test_regex_invalid_f64() {
     params.assign("regex", Value::F64(123456.7)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("regex").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("regex").unwrap()),
                vec!["The regex field must match the pattern \"^\\d+$\".".to_owned()]);
 }
 
@@ -130,7 +133,7 @@ fn test_regex_valid_blank() {
     let params = Map::new();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
@@ -144,12 +147,12 @@ fn test_regex_invalid_null() {
     params.assign("regex", Value::Null).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("regex").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("regex").unwrap()),
                vec!["The regex field must match the pattern \"^\\d+$\".".to_owned()]);
 }
 
@@ -161,7 +164,7 @@ fn test_regex_valid_nested() {
     params.assign("test", Value::Map(test)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("test.regex", vec![Rule::Regex(r"^\d+$")]);
+    rules.insert("test.regex", vec![Rule::Regex(r"^\d+$".into())]);
 
     let result = validate(&rules, params);
 
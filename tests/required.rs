@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -61,7 +64,7 @@ fn test_required_invalid_empty_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -105,7 +108,7 @@ fn test_required_invalid_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -138,7 +141,7 @@ fn test_required_invalid_object() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -152,7 +155,7 @@ fn test_required_invalid_blank() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -167,7 +170,7 @@ fn test_required_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -13,7 +16,7 @@ fn test_required_if_valid() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredIf("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredIf("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
@@ -30,12 +33,12 @@ fn test_required_if_invalid() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredIf("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredIf("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -47,7 +50,7 @@ fn test_required_if_valid_not_match() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredIf("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredIf("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
@@ -63,7 +66,7 @@ fn test_required_if_valid_other_blank() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredIf("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredIf("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
@@ -79,12 +82,12 @@ fn test_required_if_invalid_blank() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredIf("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredIf("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -96,12 +99,12 @@ fn test_required_if_invalid_null() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredIf("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredIf("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -115,7 +118,7 @@ fn test_required_if_valid_nested() {
 
     let mut rules = BTreeMap::new();
     rules.insert("test.required",
-                 vec![Rule::RequiredIf("test.other", Value::Boolean(true))]);
+                 vec![Rule::RequiredIf("test.other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
@@ -123,3 +126,21 @@ fn test_required_if_valid_nested() {
     assert_eq!(result.unwrap().find(&["test", "required"]).unwrap(),
                &Value::String("true".to_owned()));
 }
+
+#[test]
+fn test_required_if_invalid_any_value() {
+    let mut params = Map::new();
+    params.assign("other", Value::String("charity".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("required",
+                 vec![Rule::RequiredIf("other",
+                                       vec![Value::String("business".to_owned()),
+                                            Value::String("charity".to_owned())])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
+               vec!["The required field is required.".to_owned()]);
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -13,7 +16,7 @@ fn test_required_unless_valid() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredUnless("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredUnless("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
@@ -30,12 +33,12 @@ fn test_required_unless_invalid() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredUnless("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredUnless("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -47,7 +50,7 @@ fn test_required_unless_valid_not_match() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredUnless("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredUnless("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
@@ -63,12 +66,12 @@ fn test_required_unless_invalid_other_blank() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredUnless("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredUnless("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -79,12 +82,12 @@ fn test_required_unless_invalid_blank() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredUnless("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredUnless("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -96,12 +99,12 @@ fn test_required_unless_invalid_null() {
 
     let mut rules = BTreeMap::new();
     rules.insert("required",
-                 vec![Rule::RequiredUnless("other", Value::Boolean(true))]);
+                 vec![Rule::RequiredUnless("other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -115,7 +118,7 @@ fn test_required_unless_valid_nested() {
 
     let mut rules = BTreeMap::new();
     rules.insert("test.required",
-                 vec![Rule::RequiredUnless("test.other", Value::Boolean(true))]);
+                 vec![Rule::RequiredUnless("test.other", vec![Value::Boolean(true)])]);
 
     let result = validate(&rules, params);
 
@@ -123,3 +126,19 @@ fn test_required_unless_valid_nested() {
     assert_eq!(result.unwrap().find(&["test", "required"]).unwrap(),
                &Value::String("true".to_owned()));
 }
+
+#[test]
+fn test_required_unless_valid_any_value() {
+    let mut params = Map::new();
+    params.assign("other", Value::String("charity".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("required",
+                 vec![Rule::RequiredUnless("other",
+                                           vec![Value::String("business".to_owned()),
+                                                Value::String("charity".to_owned())])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -87,7 +90,7 @@ fn test_required_with_all_invalid_two_of_two_blank() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -103,7 +106,7 @@ fn test_required_with_all_invalid() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -149,7 +152,7 @@ fn test_required_with_all_invalid_blank() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
@@ -165,7 +168,7 @@ fn test_required_with_all_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("required").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("required").unwrap()),
                vec!["The required field is required.".to_owned()]);
 }
 
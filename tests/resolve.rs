@@ -0,0 +1,197 @@
+extern crate iron_valid;
+
+use iron_valid::{CachingResolver, RecordType, ResolveError, Resolver, StaticResolver,
+                 SystemResolver};
+use std::io::{Read, Write};
+use std::net::{TcpListener, UdpSocket};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+struct Counting {
+    calls: Arc<AtomicUsize>,
+    result: Result<Vec<String>, ResolveError>,
+}
+
+impl Resolver for Counting {
+    fn resolve(&self, _: &str, _: RecordType) -> Result<Vec<String>, ResolveError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.result.clone()
+    }
+}
+
+fn counting(result: Result<Vec<String>, ResolveError>) -> (Arc<AtomicUsize>, Counting) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    (calls.clone(), Counting { calls, result })
+}
+
+/// Answers one query with the given answer records, which follow the question.
+fn nameserver(code: u8, answers: Vec<Vec<u8>>) -> SystemResolver {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let address = socket.local_addr().unwrap();
+    thread::spawn(move || {
+        let mut buffer = [0u8; 512];
+        let (len, from) = socket.recv_from(&mut buffer).unwrap();
+        let mut response = buffer[..len].to_vec();
+        response[2] = 0x81;
+        response[3] = 0x80 | code;
+        response[7] = answers.len() as u8;
+        for answer in answers {
+            response.extend_from_slice(&answer);
+        }
+        socket.send_to(&response, from).unwrap();
+    });
+    SystemResolver::new().nameserver(address).timeout(Duration::from_secs(5))
+}
+
+/// An MX answer for the name in the question, at offset 12, with `exchange` encoded as labels.
+fn mx_answer(preference: u8, exchange: &[u8]) -> Vec<u8> {
+    let mut answer = vec![0xc0, 12, 0, 15, 0, 1, 0, 0, 0x0e, 0x10, 0, exchange.len() as u8 + 2, 0,
+                          preference];
+    answer.extend_from_slice(exchange);
+    answer
+}
+
+#[test]
+fn test_static_resolver() {
+    let resolver = StaticResolver::new()
+        .record("Example.com", RecordType::A, "192.0.2.1")
+        .record("example.com", RecordType::A, "192.0.2.2")
+        .record("example.com", RecordType::Mx, "mail.example.com");
+
+    assert_eq!(resolver.resolve("example.com.", RecordType::A),
+               Ok(vec!["192.0.2.1".to_owned(), "192.0.2.2".to_owned()]));
+    assert_eq!(resolver.resolve("EXAMPLE.COM", RecordType::Mx),
+               Ok(vec!["mail.example.com".to_owned()]));
+    assert_eq!(resolver.resolve("example.com", RecordType::Aaaa), Ok(vec![]));
+    assert_eq!(resolver.resolve("example.org", RecordType::A), Ok(vec![]));
+}
+
+#[test]
+fn test_caching_resolver_caches() {
+    let (calls, inner) = counting(Ok(vec!["192.0.2.1".to_owned()]));
+    let resolver = CachingResolver::new(inner);
+
+    assert_eq!(resolver.resolve("example.com", RecordType::A),
+               Ok(vec!["192.0.2.1".to_owned()]));
+    assert_eq!(resolver.resolve("EXAMPLE.com", RecordType::A),
+               Ok(vec!["192.0.2.1".to_owned()]));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    resolver.resolve("example.com", RecordType::Mx).ok();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_caching_resolver_expires() {
+    let (calls, inner) = counting(Ok(vec![]));
+    let resolver = CachingResolver::new(inner).ttl(Duration::from_millis(10));
+
+    resolver.resolve("example.com", RecordType::A).ok();
+    thread::sleep(Duration::from_millis(20));
+    resolver.resolve("example.com", RecordType::A).ok();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_caching_resolver_capacity() {
+    let (calls, inner) = counting(Ok(vec![]));
+    let resolver = CachingResolver::new(inner).capacity(2);
+
+    for host in &["a.example", "b.example", "c.example", "a.example"] {
+        resolver.resolve(host, RecordType::A).ok();
+    }
+
+    // `a.example` was the oldest, so it was replaced by `c.example`
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    resolver.resolve("c.example", RecordType::A).ok();
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_caching_resolver_skips_errors() {
+    let (calls, inner) = counting(Err(ResolveError::Timeout));
+    let resolver = CachingResolver::new(inner);
+
+    assert_eq!(resolver.resolve("example.com", RecordType::A), Err(ResolveError::Timeout));
+    assert_eq!(resolver.resolve("example.com", RecordType::A), Err(ResolveError::Timeout));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_system_resolver_mx() {
+    let resolver = nameserver(0,
+                              vec![mx_answer(10, b"\x04mail\xc0\x0c"),
+                                   mx_answer(20, b"\x06backup\x04mail\x03net\x00")]);
+
+    assert_eq!(resolver.resolve("example.com", RecordType::Mx),
+               Ok(vec!["mail.example.com".to_owned(), "backup.mail.net".to_owned()]));
+}
+
+#[test]
+fn test_system_resolver_mx_truncated() {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let address = socket.local_addr().unwrap();
+    let listener = TcpListener::bind(address).unwrap();
+    thread::spawn(move || {
+        // Answer over UDP with the truncated flag and no records
+        let mut buffer = [0u8; 512];
+        let (len, from) = socket.recv_from(&mut buffer).unwrap();
+        let mut response = buffer[..len].to_vec();
+        response[2] = 0x83;
+        response[3] = 0x80;
+        socket.send_to(&response, from).unwrap();
+
+        // Then answer in full over TCP
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut len = [0u8; 2];
+        stream.read_exact(&mut len).unwrap();
+        let mut response = vec![0u8; usize::from(len[1])];
+        stream.read_exact(&mut response).unwrap();
+        response[2] = 0x81;
+        response[3] = 0x80;
+        response[7] = 1;
+        response.extend_from_slice(&mx_answer(10, b"\x04mail\xc0\x0c"));
+        stream.write_all(&[0, response.len() as u8]).unwrap();
+        stream.write_all(&response).unwrap();
+    });
+    let resolver = SystemResolver::new().nameserver(address).timeout(Duration::from_secs(5));
+
+    assert_eq!(resolver.resolve("example.com", RecordType::Mx),
+               Ok(vec!["mail.example.com".to_owned()]));
+}
+
+#[test]
+fn test_system_resolver_mx_missing_domain() {
+    let resolver = nameserver(3, vec![]);
+
+    assert_eq!(resolver.resolve("missing.example.com", RecordType::Mx), Ok(vec![]));
+}
+
+#[test]
+fn test_system_resolver_mx_server_failure() {
+    let resolver = nameserver(2, vec![]);
+
+    assert!(matches!(resolver.resolve("example.com", RecordType::Mx),
+                     Err(ResolveError::Failed(_))));
+}
+
+#[test]
+fn test_system_resolver_timeout() {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let resolver = SystemResolver::new()
+        .nameserver(socket.local_addr().unwrap())
+        .timeout(Duration::from_millis(50));
+
+    assert_eq!(resolver.resolve("example.com", RecordType::Mx), Err(ResolveError::Timeout));
+}
+
+#[test]
+fn test_system_resolver_invalid_host() {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let resolver = SystemResolver::new().nameserver(socket.local_addr().unwrap());
+
+    assert!(resolver.resolve("example..com", RecordType::Mx).is_err());
+}
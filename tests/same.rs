@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -49,7 +52,7 @@ fn test_same_invalid_string() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -80,7 +83,7 @@ fn test_same_invalid_u64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -111,7 +114,7 @@ fn test_same_invalid_i64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -142,7 +145,7 @@ fn test_same_invalid_f64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -190,7 +193,7 @@ fn test_same_invalid_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -242,7 +245,7 @@ fn test_same_invalid_object() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -274,7 +277,7 @@ fn test_same_invalid_boolean() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -302,7 +305,7 @@ fn test_same_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("same").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("same").unwrap()),
                vec!["The same field must match the other field.".to_owned()]);
 }
 
@@ -2,6 +2,9 @@ extern crate iron_valid;
 extern crate mime;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use mime::Mime;
 use params::{File, Map, Value};
@@ -15,7 +18,7 @@ fn test_size_valid_string() {
     params.assign("size", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(3)]);
+    rules.insert("size", vec![Rule::Size(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -30,7 +33,7 @@ fn test_size_valid_empty_string() {
     params.assign("size", Value::String("".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(2)]);
+    rules.insert("size", vec![Rule::Size(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -45,12 +48,12 @@ fn test_size_invalid_high_string() {
     params.assign("size", Value::String("foobar".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(2)]);
+    rules.insert("size", vec![Rule::Size(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be 2 characters.".to_owned()]);
 }
 
@@ -60,12 +63,12 @@ fn test_size_invalid_low_string() {
     params.assign("size", Value::String("foo".to_owned())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(5)]);
+    rules.insert("size", vec![Rule::Size(5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be 5 characters.".to_owned()]);
 }
 
@@ -75,7 +78,7 @@ fn test_size_valid_u64() {
     params.assign("size", Value::U64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(3)]);
+    rules.insert("size", vec![Rule::Size(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -89,12 +92,12 @@ fn test_size_invalid_high_u64() {
     params.assign("size", Value::U64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(5)]);
+    rules.insert("size", vec![Rule::Size(5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be 5.".to_owned()]);
 }
 
@@ -104,12 +107,12 @@ fn test_size_invalid_low_u64() {
     params.assign("size", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(5)]);
+    rules.insert("size", vec![Rule::Size(5.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be 5.".to_owned()]);
 }
 
@@ -119,12 +122,12 @@ fn test_size_invalid_u64_below_0() {
     params.assign("size", Value::U64(1)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(-5)]);
+    rules.insert("size", vec![Rule::Size((-5).into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be -5.".to_owned()]);
 }
 
@@ -134,7 +137,7 @@ fn test_size_valid_i64() {
     params.assign("size", Value::I64(3)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(3)]);
+    rules.insert("size", vec![Rule::Size(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -148,12 +151,12 @@ fn test_size_invalid_high_i64() {
     params.assign("size", Value::I64(10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(1)]);
+    rules.insert("size", vec![Rule::Size(1.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be 1.".to_owned()]);
 }
 
@@ -163,12 +166,12 @@ fn test_size_invalid_low_i64() {
     params.assign("size", Value::I64(-10)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(4)]);
+    rules.insert("size", vec![Rule::Size(4.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must be 4.".to_owned()]);
 }
 
@@ -178,7 +181,7 @@ fn test_size_valid_array() {
     params.assign("size", Value::Array(vec![Value::U64(1), Value::U64(2)])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(2)]);
+    rules.insert("size", vec![Rule::Size(2.into())]);
 
     let result = validate(&rules, params);
 
@@ -193,7 +196,7 @@ fn test_size_valid_empty_array() {
     params.assign("size", Value::Array(vec![])).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(4)]);
+    rules.insert("size", vec![Rule::Size(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -210,12 +213,12 @@ fn test_size_invalid_high_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(2)]);
+    rules.insert("size", vec![Rule::Size(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have 2 items.".to_owned()]);
 }
 
@@ -227,12 +230,12 @@ fn test_size_invalid_low_array() {
         .ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(8)]);
+    rules.insert("size", vec![Rule::Size(8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have 8 items.".to_owned()]);
 }
 
@@ -247,7 +250,7 @@ fn test_size_valid_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(4)]);
+    rules.insert("size", vec![Rule::Size(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -261,7 +264,7 @@ fn test_size_valid_empty_object() {
     params.assign("size", Value::Map(Map::new())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(4)]);
+    rules.insert("size", vec![Rule::Size(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -280,12 +283,12 @@ fn test_size_invalid_high_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(2)]);
+    rules.insert("size", vec![Rule::Size(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have 2 items.".to_owned()]);
 }
 
@@ -299,12 +302,12 @@ fn test_size_invalid_low_object() {
     params.assign("size", Value::Map(items.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(8)]);
+    rules.insert("size", vec![Rule::Size(8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have 8 items.".to_owned()]);
 }
 
@@ -314,13 +317,13 @@ fn test_size_valid_file() {
     let file = File {
         path: PathBuf::from("files/temp_size_3914b.txt"),
         filename: None,
-        size: 3914,
+        size: 3072,
         content_type: Mime::from_str("text/plain").unwrap(),
     };
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(3)]);
+    rules.insert("size", vec![Rule::Size(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -328,6 +331,25 @@ fn test_size_valid_file() {
     assert_eq!(result.unwrap().find(&["size"]).unwrap(), &Value::File(file));
 }
 
+#[test]
+fn test_size_invalid_partial_kilobyte_file() {
+    let mut params = Map::new();
+    let file = File {
+        path: PathBuf::from("files/temp_size_3914b.txt"),
+        filename: None,
+        size: 3914,
+        content_type: Mime::from_str("text/plain").unwrap(),
+    };
+    params.assign("size", Value::File(file.clone())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("size", vec![Rule::Size(3.into())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_size_invalid_high_file() {
     let mut params = Map::new();
@@ -340,12 +362,12 @@ fn test_size_invalid_high_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(2)]);
+    rules.insert("size", vec![Rule::Size(2.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size must be 2 kilobytes.".to_owned()]);
 }
 
@@ -361,12 +383,12 @@ fn test_size_invalid_low_file() {
     params.assign("size", Value::File(file.clone())).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(8)]);
+    rules.insert("size", vec![Rule::Size(8.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size must be 8 kilobytes.".to_owned()]);
 }
 
@@ -375,7 +397,7 @@ fn test_size_valid_blank() {
     let params = Map::new();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(4)]);
+    rules.insert("size", vec![Rule::Size(4.into())]);
 
     let result = validate(&rules, params);
 
@@ -389,12 +411,12 @@ fn test_size_invalid_null() {
     params.assign("size", Value::Null).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("size", vec![Rule::Size(4)]);
+    rules.insert("size", vec![Rule::Size(4.into())]);
 
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("size").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("size").unwrap()),
                vec!["The size field must have a size of 4.".to_owned()]);
 }
 
@@ -406,7 +428,7 @@ fn test_size_valid_nested() {
     params.assign("test", Value::Map(test)).ok();
 
     let mut rules = BTreeMap::new();
-    rules.insert("test.size", vec![Rule::Size(3)]);
+    rules.insert("test.size", vec![Rule::Size(3.into())]);
 
     let result = validate(&rules, params);
 
@@ -0,0 +1,96 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{EmailOptions, Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_sometimes_valid_missing() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sometimes",
+                 vec![Rule::Sometimes, Rule::Required, Rule::Email(EmailOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["sometimes"]), None);
+}
+
+#[test]
+fn test_sometimes_valid_present() {
+    let mut params = Map::new();
+    params.assign("sometimes", Value::String("foo@example.com".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sometimes",
+                 vec![Rule::Sometimes, Rule::Required, Rule::Email(EmailOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_sometimes_invalid_present() {
+    let mut params = Map::new();
+    params.assign("sometimes", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sometimes",
+                 vec![Rule::Sometimes, Rule::Required, Rule::Email(EmailOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("sometimes").unwrap()),
+               vec!["The sometimes field must contain a valid email address.".to_owned()]);
+}
+
+#[test]
+fn test_sometimes_invalid_empty_string() {
+    let mut params = Map::new();
+    params.assign("sometimes", Value::String("".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sometimes", vec![Rule::Sometimes, Rule::Required]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("sometimes").unwrap()),
+               vec!["The sometimes field is required.".to_owned()]);
+}
+
+#[test]
+fn test_sometimes_invalid_null() {
+    let mut params = Map::new();
+    params.assign("sometimes", Value::Null).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("sometimes", vec![Rule::Sometimes, Rule::Required]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("sometimes").unwrap()),
+               vec!["The sometimes field is required.".to_owned()]);
+}
+
+#[test]
+fn test_sometimes_nested_missing() {
+    let mut params = Map::new();
+    params.assign("user[name]", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("user.email", vec![Rule::Sometimes, Rule::Required]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
@@ -1,6 +1,9 @@
 extern crate iron_valid;
 extern crate params;
 
+mod common;
+
+use common::messages;
 use iron_valid::{Rule, validate};
 use params::{Map, Value};
 use std::collections::BTreeMap;
@@ -61,7 +64,7 @@ fn test_string_invalid_u64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("string").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("string").unwrap()),
                vec!["The string field must be a string.".to_owned()]);
 }
 
@@ -76,7 +79,7 @@ fn test_string_invalid_i64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("string").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("string").unwrap()),
                vec!["The string field must be a string.".to_owned()]);
 }
 
@@ -91,7 +94,7 @@ fn test_string_invalid_f64() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("string").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("string").unwrap()),
                vec!["The string field must be a string.".to_owned()]);
 }
 
@@ -108,7 +111,7 @@ fn test_string_invalid_array() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("string").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("string").unwrap()),
                vec!["The string field must be a string.".to_owned()]);
 }
 
@@ -127,7 +130,7 @@ fn test_string_invalid_object() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("string").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("string").unwrap()),
                vec!["The string field must be a string.".to_owned()]);
 }
 
@@ -155,7 +158,7 @@ fn test_string_invalid_null() {
     let result = validate(&rules, params);
 
     assert!(result.is_err());
-    assert_eq!(*result.unwrap_err().get("string").unwrap(),
+    assert_eq!(messages(result.unwrap_err().get("string").unwrap()),
                vec!["The string field must be a string.".to_owned()]);
 }
 
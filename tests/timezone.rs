@@ -0,0 +1,154 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{ParseError, Rule, TimezoneOptions, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn validate_value(value: Value, options: TimezoneOptions) -> Result<Map, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("timezone", value).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("timezone", vec![Rule::Timezone(options)]);
+
+    validate(&rules, params).map_err(|errors| {
+        errors.get("timezone").unwrap().iter().map(|error| error.to_string()).collect()
+    })
+}
+
+#[test]
+fn test_timezone_valid_zone() {
+    let result = validate_value(Value::String("America/New_York".to_owned()),
+                                TimezoneOptions::new());
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["timezone"]).unwrap(),
+               &Value::String("America/New_York".to_owned()));
+}
+
+#[test]
+fn test_timezone_valid_link() {
+    let result = validate_value(Value::String("US/Eastern".to_owned()), TimezoneOptions::new());
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["timezone"]).unwrap(),
+               &Value::String("US/Eastern".to_owned()));
+}
+
+#[test]
+fn test_timezone_valid_link_canonicalized() {
+    let options = TimezoneOptions::new().canonicalize(true);
+
+    let result = validate_value(Value::String("US/Eastern".to_owned()), options);
+    assert_eq!(result.unwrap().find(&["timezone"]).unwrap(),
+               &Value::String("America/New_York".to_owned()));
+
+    let result = validate_value(Value::String("Asia/Calcutta".to_owned()), options);
+    assert_eq!(result.unwrap().find(&["timezone"]).unwrap(),
+               &Value::String("Asia/Kolkata".to_owned()));
+
+    let result = validate_value(Value::String("Europe/Paris".to_owned()), options);
+    assert_eq!(result.unwrap().find(&["timezone"]).unwrap(),
+               &Value::String("Europe/Paris".to_owned()));
+}
+
+#[test]
+fn test_timezone_valid_utc() {
+    let result = validate_value(Value::String("UTC".to_owned()), TimezoneOptions::new());
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_timezone_valid_empty() {
+    let result = validate_value(Value::String("".to_owned()), TimezoneOptions::new());
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_timezone_invalid_name() {
+    let result = validate_value(Value::String("Mars/Olympus_Mons".to_owned()),
+                                TimezoneOptions::new());
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err(),
+               vec!["The timezone field must be a valid time zone.".to_owned()]);
+}
+
+#[test]
+fn test_timezone_invalid_case() {
+    let result = validate_value(Value::String("america/new_york".to_owned()),
+                                TimezoneOptions::new());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_timezone_invalid_type() {
+    let result = validate_value(Value::I64(5), TimezoneOptions::new());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_timezone_invalid_offset_by_default() {
+    let result = validate_value(Value::String("+05:30".to_owned()), TimezoneOptions::new());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_timezone_valid_offsets() {
+    let options = TimezoneOptions::new().offsets(true);
+
+    for offset in &["+05:30", "-08:00", "+0545", "-1200", "+14:00"] {
+        let result = validate_value(Value::String(offset.to_string()), options);
+        assert!(result.is_ok(), "{} should be valid", offset);
+        assert_eq!(result.unwrap().find(&["timezone"]).unwrap(),
+                   &Value::String(offset.to_string()));
+    }
+}
+
+#[test]
+fn test_timezone_valid_offset_canonicalized() {
+    let options = TimezoneOptions::new().offsets(true).canonicalize(true);
+    let result = validate_value(Value::String("-0800".to_owned()), options);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["timezone"]).unwrap(),
+               &Value::String("-08:00".to_owned()));
+}
+
+#[test]
+fn test_timezone_invalid_offsets() {
+    let options = TimezoneOptions::new().offsets(true);
+
+    for offset in &["05:30", "+5:30", "+15:00", "+05:60", "+05-30", "+0530x", "UTC+05:30",
+                    "+1é00", "+14:01", "+14:59", "-12:01", "-14:00", "-1430"] {
+        let result = validate_value(Value::String(offset.to_string()), options);
+        assert!(result.is_err(), "{} should be invalid", offset);
+    }
+}
+
+#[test]
+fn test_timezone_parse() {
+    match Rule::parse("timezone") {
+        Ok(Rule::Timezone(options)) => assert_eq!(options, TimezoneOptions::new()),
+        other => panic!("Unexpected result {:?}", other),
+    }
+    match Rule::parse("timezone:canonical,offset") {
+        Ok(Rule::Timezone(options)) => {
+            assert_eq!(options, TimezoneOptions::new().canonicalize(true).offsets(true))
+        }
+        other => panic!("Unexpected result {:?}", other),
+    }
+    match Rule::parse("timezone:all") {
+        Err(ParseError::InvalidArgument { ref rule, ref argument }) => {
+            assert_eq!(rule, "timezone");
+            assert_eq!(argument, "all");
+        }
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
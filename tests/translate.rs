@@ -0,0 +1,162 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Catalog, CatalogError, EmailOptions, Options, Rule, Translator, plural_category,
+                 validate_with};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+const GERMAN: &str = r#"{
+    "required": "Das Feld :attribute ist erforderlich.",
+    "max.array": {
+        "one": "Das Feld :attribute darf höchstens :max Element haben.",
+        "other": "Das Feld :attribute darf höchstens :max Elemente haben."
+    }
+}"#;
+
+#[test]
+fn test_translate_catalog() {
+    let mut params = Map::new();
+    params.assign("tags[]", Value::String("a".to_owned())).ok();
+    params.assign("tags[]", Value::String("b".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Required]);
+    rules.insert("tags", vec![Rule::Max(1.into())]);
+
+    let catalog = Catalog::from_json("de", GERMAN).unwrap();
+    let options = Options::new().translator(catalog).locale("de-DE");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(messages(errors.get("name").unwrap()),
+               vec!["Das Feld name ist erforderlich.".to_owned()]);
+    assert_eq!(messages(errors.get("tags").unwrap()),
+               vec!["Das Feld tags darf höchstens 1 Element haben.".to_owned()]);
+}
+
+#[test]
+fn test_translate_fallback_chain() {
+    let mut params = Map::new();
+    params.assign("email", Value::String("foo".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Required]);
+    rules.insert("email", vec![Rule::Email(EmailOptions::new())]);
+
+    let mut catalog = Catalog::new();
+    catalog.insert("pt", "required", "O campo :attribute é obrigatório.");
+    let options = Options::new().translator(catalog).locale("pt-BR");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(messages(errors.get("name").unwrap()),
+               vec!["O campo name é obrigatório.".to_owned()]);
+    assert_eq!(messages(errors.get("email").unwrap()),
+               vec!["The email field must contain a valid email address.".to_owned()]);
+}
+
+#[test]
+fn test_translate_english_plurals() {
+    let mut params = Map::new();
+    params.assign("one[]", Value::U64(1)).ok();
+    params.assign("one[]", Value::U64(2)).ok();
+    params.assign("two[]", Value::U64(1)).ok();
+    params.assign("two[]", Value::U64(2)).ok();
+    params.assign("two[]", Value::U64(3)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("one", vec![Rule::Max(1.into())]);
+    rules.insert("two", vec![Rule::Max(2.into())]);
+
+    let result = validate_with(&rules, params, &Options::new());
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(messages(errors.get("one").unwrap()),
+               vec!["The one field must have no greater than 1 item.".to_owned()]);
+    assert_eq!(messages(errors.get("two").unwrap()),
+               vec!["The two field must have no greater than 2 items.".to_owned()]);
+}
+
+#[test]
+fn test_translate_custom_message_overrides_locale() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Required]);
+
+    let options = Options::new()
+        .translator(Catalog::from_json("de", GERMAN).unwrap())
+        .locale("de")
+        .message("name.required", "Bitte :attribute angeben.");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("name").unwrap()),
+               vec!["Bitte name angeben.".to_owned()]);
+}
+
+struct Shouting;
+
+impl Translator for Shouting {
+    fn translate(&self, locale: &str, key: &str, _count: Option<f64>) -> Option<String> {
+        if locale == "en" {
+            Some(format!("{} FAILED FOR :attribute", key.to_uppercase()))
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_translate_custom_translator() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("name", vec![Rule::Required]);
+
+    let options = Options::new().translator(Shouting).locale("ja");
+    let result = validate_with(&rules, params, &options);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("name").unwrap()),
+               vec!["REQUIRED FAILED FOR name".to_owned()]);
+}
+
+#[test]
+fn test_translate_invalid_catalog() {
+    match Catalog::from_json("de", "{") {
+        Err(CatalogError::Json(_)) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match Catalog::from_json("de", "[]") {
+        Err(CatalogError::NotAnObject) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match Catalog::from_json("de", r#"{"max.array": {"one": 1}}"#) {
+        Err(CatalogError::InvalidEntry(ref key)) => assert_eq!(key, "max.array"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_translate_plural_category() {
+    assert_eq!(plural_category("en", 1f64), "one");
+    assert_eq!(plural_category("en", 0f64), "other");
+    assert_eq!(plural_category("en-GB", 1.5f64), "other");
+    assert_eq!(plural_category("ja", 1f64), "other");
+    assert_eq!(plural_category("fr", 0f64), "one");
+    assert_eq!(plural_category("pt-BR", 0f64), "one");
+    assert_eq!(plural_category("pt-PT", 0f64), "other");
+    assert_eq!(plural_category("ru", 21f64), "one");
+    assert_eq!(plural_category("ru", 3f64), "few");
+    assert_eq!(plural_category("ru", 12f64), "many");
+    assert_eq!(plural_category("pl", 22f64), "few");
+    assert_eq!(plural_category("ar", 2f64), "two");
+}
@@ -0,0 +1,109 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_ulid_valid() {
+    let mut params = Map::new();
+    params.assign("id", Value::String("01ARZ3NDEKTSV4RRFFQ69G5FAV".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Ulid]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["id"]).unwrap(),
+               &Value::String("01ARZ3NDEKTSV4RRFFQ69G5FAV".to_owned()));
+}
+
+#[test]
+fn test_ulid_valid_normalized() {
+    let mut params = Map::new();
+    params.assign("id", Value::String("01arz3ndektsv4rrffq69g5fav".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Ulid]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["id"]).unwrap(),
+               &Value::String("01ARZ3NDEKTSV4RRFFQ69G5FAV".to_owned()));
+}
+
+#[test]
+fn test_ulid_valid_max() {
+    let mut params = Map::new();
+    params.assign("id", Value::String("7ZZZZZZZZZZZZZZZZZZZZZZZZZ".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Ulid]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_ulid_invalid_timestamp_overflow() {
+    let mut params = Map::new();
+    params.assign("id", Value::String("80000000000000000000000000".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Ulid]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("id").unwrap()),
+               vec!["The id field must be a valid ULID.".to_owned()]);
+}
+
+#[test]
+fn test_ulid_invalid_characters() {
+    for value in &["01ARZ3NDEKTSV4RRFFQ69G5FAI",
+                   "01ARZ3NDEKTSV4RRFFQ69G5FAL",
+                   "01ARZ3NDEKTSV4RRFFQ69G5FAO",
+                   "01ARZ3NDEKTSV4RRFFQ69G5FAU",
+                   "01ARZ3NDEKTSV4RRFFQ69G5FA-"] {
+        let mut params = Map::new();
+        params.assign("id", Value::String(value.to_string())).ok();
+
+        let mut rules = BTreeMap::new();
+        rules.insert("id", vec![Rule::Ulid]);
+
+        assert!(validate(&rules, params).is_err(), "{}", value);
+    }
+}
+
+#[test]
+fn test_ulid_invalid_length() {
+    let mut params = Map::new();
+    params.assign("id", Value::String("01ARZ3NDEKTSV4RRFFQ69G5FA".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Ulid]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_ulid_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Ulid]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,58 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: Value) -> Result<(), Vec<String>> {
+    let mut params = Map::new();
+    params.assign("code", value).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("code", vec![Rule::Uppercase]);
+
+    validate(&rules, params)
+        .map(|_| ())
+        .map_err(|errors| errors["code"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_uppercase_valid() {
+    for value in &["HELLO-WORLD", "STRASSE", "ΕΛΛΗΝΙΚΆ", "123_!?"] {
+        assert_eq!(check(Value::String(value.to_string())), Ok(()), "{}", value);
+    }
+}
+
+#[test]
+fn test_uppercase_invalid() {
+    // `ß` has no single uppercase form, so it uppercases to `SS`.
+    for value in &["Hello", "STRAßE", "ΕΛΛΗΝΙΚά", "ǅ"] {
+        assert_eq!(check(Value::String(value.to_string())),
+                   Err(vec!["The code field must be uppercase.".to_owned()]),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_uppercase_invalid_type() {
+    assert!(check(Value::U64(42)).is_err());
+}
+
+#[test]
+fn test_uppercase_valid_empty() {
+    assert!(check(Value::String(String::new())).is_ok());
+}
+
+#[test]
+fn test_uppercase_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("code", Rule::parse_list("uppercase").unwrap());
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
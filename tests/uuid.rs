@@ -0,0 +1,130 @@
+extern crate iron_valid;
+extern crate params;
+
+use iron_valid::{Rule, UuidOptions, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+fn check(value: &str, options: UuidOptions) -> Result<Value, Vec<String>> {
+    let mut params = Map::new();
+    params.assign("id", Value::String(value.to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Uuid(options)]);
+
+    validate(&rules, params)
+        .map(|values| values.find(&["id"]).unwrap().clone())
+        .map_err(|errors| errors["id"].iter().map(|error| error.to_string()).collect())
+}
+
+#[test]
+fn test_uuid_valid() {
+    let result = check("67e55044-10b1-426f-9247-bb680e5fe0c8", UuidOptions::new());
+
+    assert_eq!(result,
+               Ok(Value::String("67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned())));
+}
+
+#[test]
+fn test_uuid_valid_normalized() {
+    let canonical = Value::String("67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned());
+    let forms = ["67E55044-10B1-426F-9247-BB680E5FE0C8",
+                 "67e5504410b1426f9247bb680e5fe0c8",
+                 "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+                 "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+                 "URN:UUID:67E55044-10B1-426F-9247-BB680E5FE0C8"];
+    for form in &forms {
+        assert_eq!(check(form, UuidOptions::new()), Ok(canonical.clone()), "{}", form);
+    }
+}
+
+#[test]
+fn test_uuid_valid_nil() {
+    let result = check("00000000-0000-0000-0000-000000000000", UuidOptions::new());
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_uuid_invalid() {
+    let invalid = ["67e55044-10b1-426f-9247-bb680e5fe0c",
+                   "67e55044-10b1-426f-9247-bb680e5fe0c8a",
+                   "67e5504410b1-426f-9247-bb680e5fe0c8",
+                   "67e55044-10b1-426f-9247-bb680e5fe0cg",
+                   "67e55044_10b1_426f_9247_bb680e5fe0c8",
+                   "{67e55044-10b1-426f-9247-bb680e5fe0c8",
+                   "ürn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+                   "not a uuid"];
+    for value in &invalid {
+        assert_eq!(check(value, UuidOptions::new()),
+                   Err(vec!["The id field must be a valid UUID.".to_owned()]),
+                   "{}",
+                   value);
+    }
+}
+
+#[test]
+fn test_uuid_invalid_type() {
+    let mut params = Map::new();
+    params.assign("id", Value::U64(4)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Uuid(UuidOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_uuid_versions() {
+    let options = UuidOptions::new().version(4).version(7);
+
+    assert!(check("67e55044-10b1-426f-9247-bb680e5fe0c8", options).is_ok());
+    assert!(check("01890a5d-ac96-774b-bcce-b302099a8057", options).is_ok());
+    assert!(check("c232ab00-9414-11ec-b3c8-9f6bdeced846", options).is_err());
+    assert!(check("00000000-0000-0000-0000-000000000000", options).is_err());
+    assert!(check("c232ab00-9414-11ec-b3c8-9f6bdeced846", UuidOptions::new().version(1)).is_ok());
+}
+
+#[test]
+fn test_uuid_versions_require_variant() {
+    let options = UuidOptions::new().version(4);
+
+    assert!(check("67e55044-10b1-426f-c247-bb680e5fe0c8", options).is_err());
+}
+
+#[test]
+fn test_uuid_canonical() {
+    let options = UuidOptions::new().canonical(true);
+
+    assert!(check("67e55044-10b1-426f-9247-bb680e5fe0c8", options).is_ok());
+    assert!(check("67E55044-10B1-426F-9247-BB680E5FE0C8", options).is_err());
+    assert!(check("67e5504410b1426f9247bb680e5fe0c8", options).is_err());
+}
+
+#[test]
+fn test_uuid_valid_blank() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("id", vec![Rule::Uuid(UuidOptions::new())]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_uuid_parse() {
+    let rules = Rule::parse_list("uuid:4,7,canonical").unwrap();
+
+    match rules[0] {
+        Rule::Uuid(options) => {
+            assert_eq!(options, UuidOptions::new().version(4).version(7).canonical(true))
+        }
+        ref other => panic!("Unexpected rule {:?}", other),
+    }
+    assert!(Rule::parse("uuid:9").is_err());
+    assert!(Rule::parse("uuid:lower").is_err());
+}
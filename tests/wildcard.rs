@@ -0,0 +1,203 @@
+extern crate iron_valid;
+extern crate params;
+
+mod common;
+
+use common::messages;
+use iron_valid::{Rule, validate};
+use params::{Map, Value};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_wildcard_valid_array_of_maps() {
+    let mut params = Map::new();
+    params.assign("items[][name]", Value::String("foo".to_owned())).ok();
+    params.assign("items[][name]", Value::String("bar".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.name", vec![Rule::Required, Rule::Alpha]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_wildcard_invalid_array_of_maps() {
+    let mut params = Map::new();
+    params.assign("items[][name]", Value::String("foo".to_owned())).ok();
+    params.assign("items[][name]", Value::String("".to_owned())).ok();
+    params.assign("items[][name]", Value::String("b4r".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.name", vec![Rule::Required, Rule::Alpha]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(messages(errors.get("items.1.name").unwrap()),
+               vec!["The name field is required.".to_owned()]);
+    assert_eq!(messages(errors.get("items.2.name").unwrap()),
+               vec!["The name field may only contain alphabetic characters.".to_owned()]);
+}
+
+#[test]
+fn test_wildcard_invalid_missing_key() {
+    let mut params = Map::new();
+    params.assign("items[][name]", Value::String("foo".to_owned())).ok();
+    params.assign("items[][qty]", Value::U64(1)).ok();
+    params.assign("items[][qty]", Value::U64(2)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.name", vec![Rule::Required]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    assert_eq!(messages(result.unwrap_err().get("items.1.name").unwrap()),
+               vec!["The name field is required.".to_owned()]);
+}
+
+#[test]
+fn test_wildcard_valid_scalar_array() {
+    let mut params = Map::new();
+    params.assign("tags[]", Value::String("1".to_owned())).ok();
+    params.assign("tags[]", Value::String("2".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("tags.*", vec![Rule::Integer]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().find(&["tags"]).unwrap(),
+               &Value::Array(vec![Value::U64(1), Value::U64(2)]));
+}
+
+#[test]
+fn test_wildcard_valid_missing_array() {
+    let params = Map::new();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.name", vec![Rule::Required]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_wildcard_valid_map_keys() {
+    let mut params = Map::new();
+    params.assign("prices[small]", Value::String("1.5".to_owned())).ok();
+    params.assign("prices[large]", Value::String("3".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("prices.*", vec![Rule::Numeric]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+    let values = result.unwrap();
+    assert_eq!(values.find(&["prices", "small"]).unwrap(), &Value::F64(1.5));
+    assert_eq!(values.find(&["prices", "large"]).unwrap(), &Value::U64(3));
+}
+
+#[test]
+fn test_wildcard_invalid_nested_arrays() {
+    let mut line = Map::new();
+    line.assign("sku", Value::String("abc".to_owned())).ok();
+    let mut bad_line = Map::new();
+    bad_line.assign("sku", Value::String("a-b".to_owned())).ok();
+    let mut first = Map::new();
+    first.assign("lines", Value::Array(vec![Value::Map(line.clone())])).ok();
+    let mut second = Map::new();
+    second.assign("lines", Value::Array(vec![Value::Map(bad_line), Value::Map(line)])).ok();
+    let mut params = Map::new();
+    params.assign("orders", Value::Array(vec![Value::Map(first), Value::Map(second)])).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("orders.*.lines.*.sku", vec![Rule::Alpha]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(messages(errors.get("orders.1.lines.0.sku").unwrap()),
+               vec!["The sku field may only contain alphabetic characters.".to_owned()]);
+}
+
+#[test]
+fn test_wildcard_valid_same_resolves_index() {
+    let mut params = Map::new();
+    params.assign("items[][price]", Value::U64(5)).ok();
+    params.assign("items[][confirm]", Value::U64(5)).ok();
+    params.assign("items[][price]", Value::U64(7)).ok();
+    params.assign("items[][confirm]", Value::U64(7)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.price", vec![Rule::Same("items.*.confirm")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_wildcard_invalid_same_resolves_index() {
+    let mut params = Map::new();
+    params.assign("items[][price]", Value::U64(5)).ok();
+    params.assign("items[][confirm]", Value::U64(5)).ok();
+    params.assign("items[][price]", Value::U64(7)).ok();
+    params.assign("items[][confirm]", Value::U64(5)).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.price", vec![Rule::Same("items.*.confirm")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(messages(errors.get("items.1.price").unwrap()),
+               vec!["The price field must match the confirm field.".to_owned()]);
+}
+
+#[test]
+fn test_wildcard_invalid_required_if_resolves_index() {
+    let mut params = Map::new();
+    params.assign("contacts[][type]", Value::String("personal".to_owned())).ok();
+    params.assign("contacts[][type]", Value::String("business".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("contacts.*.company",
+                 vec![Rule::RequiredIf("contacts.*.type",
+                                       vec![Value::String("business".to_owned())])]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(messages(errors.get("contacts.1.company").unwrap()),
+               vec!["The company field is required.".to_owned()]);
+}
+
+#[test]
+fn test_wildcard_valid_in_array_outside_wildcard() {
+    let mut params = Map::new();
+    params.assign("allowed[]", Value::String("red".to_owned())).ok();
+    params.assign("allowed[]", Value::String("blue".to_owned())).ok();
+    params.assign("items[][color]", Value::String("blue".to_owned())).ok();
+    params.assign("items[][color]", Value::String("red".to_owned())).ok();
+
+    let mut rules = BTreeMap::new();
+    rules.insert("items.*.color", vec![Rule::InArray("allowed")]);
+
+    let result = validate(&rules, params);
+
+    assert!(result.is_ok());
+}